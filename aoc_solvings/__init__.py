@@ -0,0 +1,132 @@
+"""Advent of Code solutions, usable as a library as well as scripts.
+
+`solve(2016, 5, puzzle_input)` runs a day's solution on the given input without
+going through its script, and `load_day` gives the `DayN` instance itself.
+`register` swaps in another implementation of a day, such as an experimental
+rewrite kept outside this repository. Installed packages can do the same by naming
+the class under the `aoc_solvings.solutions` entry point group, with entry point
+names like `2016-5`, which `discover` registers.
+"""
+# Standard Library
+import importlib
+from importlib.metadata import entry_points
+from pathlib import Path
+import re
+import sys
+
+ROOT = Path(__file__).resolve().parent
+# day modules import `common` as a top level package, the same as when run as scripts
+if str(ROOT) not in sys.path:
+    sys.path.insert(0, str(ROOT))
+
+# My Modules
+from common.template import Day  # noqa: E402
+
+__all__ = [
+    "Day",
+    "discover",
+    "has_solution",
+    "is_registered",
+    "load_day",
+    "register",
+    "solve",
+]
+
+ENTRY_POINT_GROUP = "aoc_solvings.solutions"
+ENTRY_POINT_NAME = re.compile(r"(\d+)-(\d+)")
+
+_REGISTERED: dict[tuple[int, int], type[Day]] = {}
+# line `common.scaffold` writes into day stubs, removed once the day is solved
+_STUB_MARKER = re.compile(r"^STUB = True$", re.MULTILINE)
+
+
+def register(year: int, day: int, solution: type[Day]) -> None:
+    """Use `solution` for a day in place of the `DayN` from `src_<year>`.
+
+    Args:
+        year (int): event year
+        day (int): day of the event
+        solution (type[Day]): class to build instead, takes the same arguments
+    """
+    _REGISTERED[year, day] = solution
+
+
+class InvalidEntryPointError(Exception):
+    """Error for when a solution entry point isn't named `<year>-<day>`."""
+
+    def __init__(self, name: str, *args: object) -> None:
+        """Error message shows the entry point name."""
+        super().__init__(f"Expected entry point like 2016-5, got {name!r}", *args)
+
+
+def discover() -> list[tuple[int, int]]:
+    """Register the solutions installed packages list under `ENTRY_POINT_GROUP`.
+
+    Raises:
+        InvalidEntryPointError: if an entry point name isn't `<year>-<day>`
+
+    Returns:
+        list[tuple[int, int]]: year and day of each registered solution
+    """
+    found = []
+    for entry in entry_points(group=ENTRY_POINT_GROUP):
+        if not (match := ENTRY_POINT_NAME.fullmatch(entry.name)):
+            raise InvalidEntryPointError(entry.name)
+        year, day = map(int, match.groups())
+        register(year, day, entry.load())
+        found.append((year, day))
+    return found
+
+
+def is_registered(year: int, day: int) -> bool:
+    """Return whether a day has a solution given to `register`."""
+    return (year, day) in _REGISTERED
+
+
+def has_solution(year: int, day: int) -> bool:
+    """Return whether a day has a registered or built in solution.
+
+    Day files that still have the `STUB = True` marker from `common.scaffold` don't
+    count.
+    """
+    if (year, day) in _REGISTERED:
+        return True
+    path = ROOT / f"src_{year}" / f"day{day}.py"
+    return path.exists() and _STUB_MARKER.search(path.read_text()) is None
+
+
+def load_day(year: int, day: int, *args: object, **kwargs: object) -> Day:
+    """Return the solution for a day, `args` and `kwargs` go to its constructor.
+
+    A class given to `register` for the day takes priority over `src_<year>`.
+
+    Args:
+        year (int): event year
+        day (int): day of the event
+        *args (object): positional arguments for `DayN`
+        **kwargs (object): keyword arguments for `DayN`
+
+    Returns:
+        Day
+    """
+    if (year, day) in _REGISTERED:
+        return _REGISTERED[year, day](*args, **kwargs)
+    module = importlib.import_module(f"src_{year}.day{day}")
+    return getattr(module, f"Day{day}")(*args, **kwargs)
+
+
+def solve(
+    year: int, day: int, input_text: str, /, parts: str = "ab"
+) -> tuple[object, object]:
+    """Return answers of a day for the given input.
+
+    Args:
+        year (int): event year
+        day (int): day of the event
+        input_text (str): puzzle input
+        parts (str, optional): which parts to solve. Defaults to "ab".
+
+    Returns:
+        tuple[object, object]: part 1 and part 2 answers, None for a skipped part
+    """
+    return load_day(year, day).solve(input_text, parts=parts)
@@ -0,0 +1,121 @@
+"""Run an Advent of Code solution by year and day, called as `python -m aoc_solvings`.
+
+Usage:
+    aoc_solvings <year> <day> [<args>...]
+    aoc_solvings serve [<args>...]
+    aoc_solvings selftest [<args>...]
+
+Anything after the day is handed to that day's own script, so
+`python -m aoc_solvings 2016 5 --example --parts=a` works the same as running
+`src_2016/day5.py --example --parts=a` directly. Days given to
+`aoc_solvings.register`, or installed under the `aoc_solvings.solutions` entry
+point group, run through `aoc_solvings.load_day` instead and take the options in
+`REGISTERED_USAGE`. `serve` starts an HTTP server
+answering solve requests, see `aoc_solvings.server` for its options. `selftest`
+checks every day against its example answers, see `aoc_solvings.selftest`.
+"""
+# Standard Library
+import importlib
+from pathlib import Path
+import runpy
+import sys
+
+# External Party
+from aocd import get_data
+from docopt import docopt
+
+# My Modules
+from aoc_solvings import discover
+from aoc_solvings import is_registered
+from aoc_solvings import load_day
+from aoc_solvings import selftest
+from aoc_solvings import server
+
+ROOT = Path(__file__).resolve().parent
+
+REGISTERED_USAGE = """Run a registered day, which has no script of its own.
+
+Usage:
+    aoc_solvings <year> <day> [--example | --local] [--parts=<char>]
+
+Options:
+    --example       Use the example of the built in day rather than personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+
+
+def day_script(year: int, day: int) -> Path:
+    """Return path of the script solving a day.
+
+    Args:
+        year (int): event year
+        day (int): day of the event
+
+    Raises:
+        FileNotFoundError: if there is no solution for that day
+
+    Returns:
+        Path
+    """
+    script = ROOT / f"src_{year}" / f"day{day}.py"
+    if not script.exists():
+        raise FileNotFoundError(f"no solution for {year} day {day} at {script}")
+    return script
+
+
+def run_registered(year: int, day: int, argv: list[str]) -> None:
+    """Print answers of a registered day, they aren't submitted.
+
+    Args:
+        year (int): event year
+        day (int): day of the event
+        argv (list[str]): options from `REGISTERED_USAGE`
+    """
+    args = docopt(REGISTERED_USAGE, argv=[str(year), str(day), *argv])
+    solver_args = ()
+    if args["--example"]:
+        try:
+            module = importlib.import_module(f"src_{year}.day{day}")
+        except ModuleNotFoundError:
+            sys.exit(f"no built in example for {year} day {day}")
+        puzzle_input = module.EXAMPLE
+        solver_args = getattr(module, "EXAMPLE_ARGS", ())
+    elif args["--local"]:
+        puzzle_input = (ROOT / "data" / f"input{year}-{day}.txt").read_text()
+    else:
+        puzzle_input = get_data(day=day, year=year)
+    solver = load_day(year, day, *solver_args)
+    print(solver.solve(puzzle_input, parts=args["--parts"]))
+
+
+def main() -> None:
+    """Run the requested day, or the `serve` and `selftest` commands.
+
+    Solutions installed as entry points are registered first.
+    """
+    discover()
+    if sys.argv[1:2] == ["serve"]:
+        server.main(sys.argv[2:])
+        return
+    if sys.argv[1:2] == ["selftest"]:
+        selftest.main(sys.argv[2:])
+        return
+    args = docopt(__doc__, options_first=True)
+    year, day = int(args["<year>"]), int(args["<day>"])
+    if is_registered(year, day):
+        run_registered(year, day, args["<args>"])
+        return
+    try:
+        script = day_script(year, day)
+    except FileNotFoundError as err:
+        sys.exit(str(err))
+    # day scripts find `common` and the data folder through sys.path[0] and
+    # write their log next to themselves through sys.path[1]
+    sys.path[:1] = [str(ROOT), str(script.parent)]
+    sys.argv = [str(script), *args["<args>"]]
+    runpy.run_path(str(script), run_name="__main__")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,46 @@
+"""Combinatorial optimization helpers."""
+# Standard Library
+from collections.abc import Sequence
+
+
+def held_karp(
+    distances: Sequence[Sequence[int]], start: int = 0, *, closed: bool = False
+) -> int:
+    """Return length of the shortest route from `start` visiting every node.
+
+    Dynamic programming over (visited set, last node), so O(2^n * n^2) rather
+    than trying every permutation.
+
+    Args:
+        distances (Sequence[Sequence[int]]): distance between every pair of nodes
+        start (int, optional): node the route begins at. Defaults to 0.
+        closed (bool, optional): whether route must come back to `start`.
+            Defaults to False.
+
+    Returns:
+        int
+    """
+    size = len(distances)
+    full = (1 << size) - 1
+    # best[mask][last] is shortest route from start covering mask, ending at last
+    best = [[float("inf")] * size for _ in range(1 << size)]
+    best[1 << start][start] = 0
+    for mask in range(1 << size):
+        if not mask & (1 << start):
+            continue
+        for last, route_len in enumerate(best[mask]):
+            if route_len == float("inf"):
+                continue
+            for nxt in range(size):
+                if mask & (1 << nxt):
+                    continue
+                new_mask = mask | (1 << nxt)
+                best[new_mask][nxt] = min(
+                    best[new_mask][nxt], route_len + distances[last][nxt]
+                )
+    return int(
+        min(
+            route_len + (distances[last][start] if closed else 0)
+            for last, route_len in enumerate(best[full])
+        )
+    )
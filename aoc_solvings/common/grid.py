@@ -0,0 +1,76 @@
+"""Helpers for two dimensional grid puzzles."""
+# Standard Library
+from collections.abc import Callable
+
+TwoDimPoint = tuple[int, int]
+
+
+class RaggedGridError(Exception):
+    """Error for when the rows of a grid aren't all the same width."""
+
+    def __init__(self, line_no: int, line: str, width: int, *args: object) -> None:
+        """Error message names the first row with the wrong width."""
+        super().__init__(
+            f"Line {line_no} {line!r} is {len(line)} wide, expected {width}", *args
+        )
+
+
+def parse_grid(puzzle_input: str, *, pad: str | None = None) -> list[str]:
+    """Return the rows of a rectangular grid.
+
+    Args:
+        puzzle_input (str): grid text, one row per line
+        pad (str | None, optional): character to extend short rows with, up to the
+            widest row. Defaults to None, which rejects rows of different widths.
+
+    Raises:
+        RaggedGridError: if rows differ in width and no `pad` is given
+
+    Returns:
+        list[str]
+    """
+    rows = puzzle_input.splitlines()
+    if pad is not None:
+        width = max(map(len, rows), default=0)
+        return [row.ljust(width, pad) for row in rows]
+    for line_no, row in enumerate(rows, start=1):
+        if len(row) != len(rows[0]):
+            raise RaggedGridError(line_no, row, len(rows[0]))
+    return rows
+
+
+def render(width: int, height: int, glyph: Callable[[int, int], str]) -> str:
+    """Return the grid drawn row by row, `glyph(x, y)` gives each cell's text."""
+    return "\n".join("".join(glyph(x, y) for x in range(width)) for y in range(height))
+
+
+def segment_intersection(
+    start: TwoDimPoint,
+    end: TwoDimPoint,
+    other_start: TwoDimPoint,
+    other_end: TwoDimPoint,
+) -> TwoDimPoint | None:
+    """Return the first point along start -> end that also lies on the other segment.
+
+    Both segments must be axis aligned, endpoints are inclusive. Overlapping
+    collinear segments share many points, the one nearest `start` is returned.
+
+    Args:
+        start (TwoDimPoint): first end of the walked segment
+        end (TwoDimPoint): second end of the walked segment
+        other_start (TwoDimPoint): one end of the segment to test against
+        other_end (TwoDimPoint): other end of the segment to test against
+
+    Returns:
+        TwoDimPoint | None: None when the segments don't touch
+    """
+    bounds = []
+    for axis in range(2):
+        low = max(min(start[axis], end[axis]), min(other_start[axis], other_end[axis]))
+        high = min(max(start[axis], end[axis]), max(other_start[axis], other_end[axis]))
+        if low > high:
+            return None
+        bounds.append((low, high))
+    # shared points form a box inside the walked segment, clamp start into it
+    (low_x, high_x), (low_y, high_y) = bounds
+    return min(max(start[0], low_x), high_x), min(max(start[1], low_y), high_y)
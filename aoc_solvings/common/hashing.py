@@ -0,0 +1,71 @@
+"""Shared MD5 helpers for the brute-force hashing puzzles."""
+# Standard Library
+from collections.abc import Callable
+from collections.abc import Iterator
+from concurrent.futures import ProcessPoolExecutor
+from hashlib import md5
+import os
+from typing import Any
+
+DEFAULT_CHUNK_SIZE = 100_000
+
+
+def md5_hex(text: str) -> str:
+    """Return the lowercase hex MD5 digest of `text`."""
+    return md5(text.encode()).hexdigest()
+
+
+def stretched_md5_hex(text: str, stretch: int = 0) -> str:
+    """Return MD5 digest of `text` rehashed `stretch` additional times."""
+    digest = md5_hex(text)
+    for _ in range(stretch):
+        digest = md5(digest.encode()).hexdigest()
+    return digest
+
+
+def hash_range(salt: str, stretch: int, start: int, stop: int) -> list[str]:
+    """Return stretched digests of `salt + index` for every index in `[start, stop)`."""
+    return [
+        stretched_md5_hex(f"{salt}{index}", stretch) for index in range(start, stop)
+    ]
+
+
+def prefixed_hashes(
+    salt: str, prefix: str, start: int, stop: int
+) -> list[tuple[int, str]]:
+    """Return `(index, digest)` pairs in `[start, stop)` whose digest has `prefix`."""
+    base = md5(salt.encode())
+    found = []
+    for index in range(start, stop):
+        hasher = base.copy()
+        hasher.update(str(index).encode())
+        if (digest := hasher.hexdigest()).startswith(prefix):
+            found.append((index, digest))
+    return found
+
+
+def parallel_chunks(
+    func: Callable[..., Any],
+    *args: Any,
+    start: int = 0,
+    chunk_size: int = DEFAULT_CHUNK_SIZE,
+    max_workers: int | None = None,
+) -> Iterator[Any]:
+    """Yield `func(*args, chunk_start, chunk_stop)` for consecutive chunks forever.
+
+    Chunks are computed a batch at a time across worker processes but yielded in
+    index order, so callers can stop consuming as soon as they have their answer.
+    `func` must be a module level function so it can be sent to the workers.
+    """
+    workers = max_workers or os.cpu_count() or 1
+    with ProcessPoolExecutor(max_workers=workers) as pool:
+        while True:
+            batch_stop = start + workers * chunk_size
+            bounds = [
+                (chunk_start, chunk_start + chunk_size)
+                for chunk_start in range(start, batch_stop, chunk_size)
+            ]
+            futures = [pool.submit(func, *args, *bound) for bound in bounds]
+            for future in futures:
+                yield future.result()
+            start = batch_stop
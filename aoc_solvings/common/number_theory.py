@@ -0,0 +1,42 @@
+"""Modular arithmetic helpers shared across years."""
+# Standard Library
+from collections.abc import Iterable
+from math import gcd
+
+
+class NoSolutionError(Exception):
+    """Error for when a system of congruences is inconsistent."""
+
+    def __init__(self, *args: object) -> None:
+        """Default message and pass through args."""
+        super().__init__("Congruences have no common solution", *args)
+
+
+def crt(congruences: Iterable[tuple[int, int]]) -> tuple[int, int]:
+    """Solve a system of `x = residue (mod modulus)` congruences.
+
+    Moduli don't need to be pairwise coprime, as long as the congruences agree
+    wherever their moduli share a factor.
+
+    Args:
+        congruences (Iterable[tuple[int, int]]): pairs of residue and modulus
+
+    Raises:
+        NoSolutionError: if no integer satisfies every congruence
+
+    Returns:
+        tuple[int, int]: smallest non-negative solution and the lcm of the moduli,
+            every solution is the first plus a multiple of the second
+    """
+    solution, step = 0, 1
+    for residue, modulus in congruences:
+        common = gcd(step, modulus)
+        if (residue - solution) % common:
+            raise NoSolutionError(residue, modulus)
+        # step forward by multiples of the running lcm until residue matches
+        reduced = modulus // common
+        multiple = ((residue - solution) // common) * pow(step // common, -1, reduced)
+        solution += step * (multiple % reduced)
+        step *= reduced
+        solution %= step
+    return solution, step
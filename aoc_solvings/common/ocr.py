@@ -0,0 +1,62 @@
+"""Read the block letters that Advent of Code screens draw."""
+# Standard Library
+from collections.abc import Sequence
+
+UNKNOWN_LETTER = "?"
+
+_GLYPHS = {
+    "A": (".##.", "#..#", "#..#", "####", "#..#", "#..#"),
+    "B": ("###.", "#..#", "###.", "#..#", "#..#", "###."),
+    "C": (".##.", "#..#", "#...", "#...", "#..#", ".##."),
+    "E": ("####", "#...", "###.", "#...", "#...", "####"),
+    "F": ("####", "#...", "###.", "#...", "#...", "#..."),
+    "G": (".##.", "#..#", "#...", "#.##", "#..#", ".###"),
+    "H": ("#..#", "#..#", "####", "#..#", "#..#", "#..#"),
+    "I": ("###", ".#.", ".#.", ".#.", ".#.", "###"),
+    "J": ("..##", "...#", "...#", "...#", "#..#", ".##."),
+    "K": ("#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"),
+    "L": ("#...", "#...", "#...", "#...", "#...", "####"),
+    "O": (".##.", "#..#", "#..#", "#..#", "#..#", ".##."),
+    "P": ("###.", "#..#", "#..#", "###.", "#...", "#..."),
+    "R": ("###.", "#..#", "#..#", "###.", "#.#.", "#..#"),
+    "S": (".###", "#...", "#...", ".##.", "...#", "###."),
+    "U": ("#..#", "#..#", "#..#", "#..#", "#..#", ".##."),
+    "Y": ("#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."),
+    "Z": ("####", "...#", "..#.", ".#..", "#...", "####"),
+}
+LETTERS = {glyph: letter for letter, glyph in _GLYPHS.items()}
+
+
+def _split_glyphs(rows: Sequence[str], cell_width: int) -> list[tuple[str, ...]]:
+    """Split the screen into fixed width cells, trimming dark columns off each.
+
+    Letters can touch the next cell (`Y` is five columns wide), so cells are
+    sliced by position rather than split on dark gaps. Fully dark cells are dropped.
+    """
+    glyphs = []
+    for start in range(0, len(rows[0]) if rows else 0, cell_width):
+        columns = list(zip(*(row[start : start + cell_width] for row in rows)))
+        lit = [index for index, column in enumerate(columns) if "#" in column]
+        if lit:
+            trimmed = columns[lit[0] : lit[-1] + 1]
+            glyphs.append(tuple("".join(row) for row in zip(*trimmed)))
+    return glyphs
+
+
+def read_letters(rows: Sequence[str], lit: str = "#", cell_width: int = 5) -> str:
+    """Return the text drawn on a screen.
+
+    Args:
+        rows (Sequence[str]): each row of the screen, top to bottom
+        lit (str, optional): character used for an on pixel. Defaults to "#".
+        cell_width (int, optional): columns each letter occupies, including its
+            spacing. Defaults to 5.
+
+    Returns:
+        str: letters read left to right, unrecognized glyphs become `?`
+    """
+    normalized = ["".join("#" if ch == lit else "." for ch in row) for row in rows]
+    return "".join(
+        LETTERS.get(glyph, UNKNOWN_LETTER)
+        for glyph in _split_glyphs(normalized, cell_width)
+    )
@@ -0,0 +1,92 @@
+"""Sets of integers stored as merged inclusive ranges."""
+from __future__ import annotations
+
+# Standard Library
+from bisect import bisect_right
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Iterable
+    from collections.abc import Iterator
+
+Span = tuple[int, int]
+
+
+class RangeSet:
+    """Set of integers kept as sorted, disjoint, non-adjacent inclusive spans."""
+
+    def __init__(self, spans: Iterable[Span] = ()) -> None:
+        """Merge any overlapping or touching spans on the way in."""
+        self._spans: list[Span] = []
+        for start, end in sorted(spans):
+            if start > end:
+                continue
+            if self._spans and start <= self._spans[-1][1] + 1:
+                last_start, last_end = self._spans[-1]
+                self._spans[-1] = last_start, max(last_end, end)
+            else:
+                self._spans.append((start, end))
+
+    def __iter__(self) -> Iterator[Span]:
+        """Iterate over the merged spans in increasing order."""
+        return iter(self._spans)
+
+    def __len__(self) -> int:
+        """Return number of merged spans."""
+        return len(self._spans)
+
+    def __contains__(self, value: int) -> bool:
+        """Return whether value falls in any span."""
+        index = bisect_right(self._spans, (value, float("inf"))) - 1
+        return index >= 0 and self._spans[index][1] >= value
+
+    def __eq__(self, other: object) -> bool:
+        """Compare by covered integers."""
+        return isinstance(other, RangeSet) and self._spans == other._spans
+
+    def __repr__(self) -> str:
+        """Show the merged spans."""
+        return f"RangeSet({self._spans!r})"
+
+    def __or__(self, other: RangeSet) -> RangeSet:
+        """Return integers covered by either set."""
+        return RangeSet([*self._spans, *other._spans])
+
+    def __and__(self, other: RangeSet) -> RangeSet:
+        """Return integers covered by both sets."""
+        overlaps, mine, theirs = [], 0, 0
+        while mine < len(self._spans) and theirs < len(other._spans):
+            (start_a, end_a), (start_b, end_b) = (
+                self._spans[mine],
+                other._spans[theirs],
+            )
+            if (start := max(start_a, start_b)) <= (end := min(end_a, end_b)):
+                overlaps.append((start, end))
+            if end_a < end_b:
+                mine += 1
+            else:
+                theirs += 1
+        return RangeSet(overlaps)
+
+    def add(self, start: int, end: int) -> None:
+        """Add every integer from start to end inclusive."""
+        self._spans = RangeSet([*self._spans, (start, end)])._spans
+
+    def size(self) -> int:
+        """Return number of integers covered."""
+        return sum(end - start + 1 for start, end in self._spans)
+
+    def issuperset(self, other: RangeSet) -> bool:
+        """Return whether every integer of other is covered by this set."""
+        return (self & other) == other
+
+    def complement(self, low: int, high: int) -> RangeSet:
+        """Return integers from low to high inclusive that are not covered."""
+        gaps, next_free = [], low
+        for start, end in self._spans:
+            if start > next_free:
+                gaps.append((next_free, min(start - 1, high)))
+            next_free = max(next_free, end + 1)
+        gaps.append((next_free, high))
+        # gaps past high come out empty and are dropped on construction
+        return RangeSet(gaps)
@@ -0,0 +1,164 @@
+"""Generate a year package of Advent of Code day stubs.
+
+Usage:
+    scaffold.py <year> [--days=<count>] [--force]
+
+Options:
+    --days COUNT    Number of day stubs to generate. [default: 25]
+    --force         Overwrite day files that already exist.
+"""
+# Standard Library
+from pathlib import Path
+from string import Template
+
+# External Party
+from docopt import docopt
+
+ROOT = Path(__file__).resolve().parent.parent
+
+INIT_TEMPLATE = '__author__ = "Ryan Ozawa"\n'
+
+DAY_TEMPLATE = Template(
+    '''\
+"""Advent of Code $year Day$day problem.
+
+Usage:
+    day$day.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day$day"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+# marks the file as an unsolved stub, delete once the day is solved
+STUB = True
+
+EXAMPLE = dedent(
+    """\\
+    """
+)
+
+
+class Day$day(Day):
+    """Day $day of Advent of Code $year."""
+
+    def parse(self, puzzle_input: str) -> list[str]:
+        """Return each line of input."""
+        return puzzle_input.splitlines()
+
+    def part1(self, data: list[str]) -> int:
+        """Solve part 1.
+
+        Args:
+            data (list[str]): lines of input
+
+        Returns:
+            int
+        """
+        raise NotImplementedError
+
+    def part2(self, data: list[str]) -> int:
+        """Solve part 2.
+
+        Args:
+            data (list[str]): lines of input
+
+        Returns:
+            int
+        """
+        raise NotImplementedError
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = $day, $year
+    day = Day$day()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
+'''
+)
+
+
+def scaffold(year: int, days: int = 25, *, force: bool = False) -> list[Path]:
+    """Write the package and day stubs for a year.
+
+    Args:
+        year (int): event year, the package is named `src_<year>`
+        days (int, optional): number of day stubs. Defaults to 25.
+        force (bool, optional): overwrite existing day files. Defaults to False.
+
+    Returns:
+        list[Path]: files that were written
+    """
+    package = ROOT / f"src_{year}"
+    package.mkdir(exist_ok=True)
+    written = []
+    init = package / "__init__.py"
+    if not init.exists():
+        init.write_text(INIT_TEMPLATE)
+        written.append(init)
+    for day in range(1, days + 1):
+        path = package / f"day{day}.py"
+        if path.exists() and not force:
+            continue
+        path.write_text(DAY_TEMPLATE.substitute(year=year, day=day))
+        written.append(path)
+    return written
+
+
+if __name__ == "__main__":
+    args = docopt(__doc__)
+    for path in scaffold(
+        int(args["<year>"]), int(args["--days"]), force=args["--force"]
+    ):
+        print(f"wrote {path.relative_to(ROOT)}")
@@ -0,0 +1,64 @@
+"""Graph search helpers shared across puzzles."""
+# Standard Library
+from collections import deque
+from collections.abc import Callable
+from collections.abc import Hashable
+from collections.abc import Iterable
+from collections.abc import Iterator
+from typing import TypeVar
+
+Node = TypeVar("Node", bound=Hashable)
+
+
+def bfs(
+    start: Node,
+    neighbors: Callable[[Node], Iterable[Node]],
+    max_depth: int | None = None,
+) -> Iterator[tuple[Node, int]]:
+    """Yield every node reachable from `start` with its distance, nearest first.
+
+    Args:
+        start (Node): node to search from, yielded first with distance 0
+        neighbors (Callable[[Node], Iterable[Node]]): nodes one step from a node
+        max_depth (int | None, optional): don't expand nodes at this distance.
+            Defaults to no limit.
+
+    Yields:
+        tuple[Node, int]: node and its fewest number of steps from `start`
+    """
+    seen = {start}
+    que: deque[tuple[Node, int]] = deque([(start, 0)])
+    while que:
+        node, dist = que.popleft()
+        yield node, dist
+        if max_depth is not None and dist >= max_depth:
+            continue
+        for neighbor in neighbors(node):
+            if neighbor not in seen:
+                seen.add(neighbor)
+                que.append((neighbor, dist + 1))
+
+
+def bfs_distance(
+    start: Node,
+    neighbors: Callable[[Node], Iterable[Node]],
+    is_goal: Callable[[Node], bool],
+) -> int | None:
+    """Return fewest steps from `start` to a goal node, None if unreachable."""
+    return next((dist for node, dist in bfs(start, neighbors) if is_goal(node)), None)
+
+
+def dfs(start: Node, neighbors: Callable[[Node], Iterable[Node]]) -> Iterator[Node]:
+    """Yield every node reachable from `start`, exploring deepest first.
+
+    Uses an explicit stack so long paths can't hit the recursion limit.
+    """
+    seen = {start}
+    stack = [start]
+    while stack:
+        node = stack.pop()
+        yield node
+        for neighbor in neighbors(node):
+            if neighbor not in seen:
+                seen.add(neighbor)
+                stack.append(neighbor)
@@ -11,9 +11,23 @@ class AnswerNotFoundError(Exception):
         super().__init__("Correct answer couldn't be found", *args)
 
 
+def normalize_input(puzzle_input: str) -> str:
+    """Drop any byte order mark, use Unix line endings and trim trailing blank lines."""
+    lines = puzzle_input.removeprefix("\ufeff").replace("\r\n", "\n").split("\n")
+    while lines and not lines[-1].strip():
+        lines.pop()
+    return "\n".join(lines)
+
+
 class Day(ABC):
     """Basic template for Advent of Code challenges."""
 
+    # days that care about the raw bytes of their input can turn this off
+    NORMALIZE_INPUT = True
+    # days whose parts change the parsed data, even if they put it back, turn this
+    # on so callers reusing a parse hand them a copy
+    CHANGES_DATA = False
+
     @abstractmethod
     def parse(self, puzzle_input):
         """Parse input."""
@@ -26,9 +40,18 @@ class Day(ABC):
     def part2(self, data):
         """Solve part 2."""
 
+    def prepare(self, puzzle_input):
+        """Return parsed input, normalized first unless the day turns that off."""
+        if self.NORMALIZE_INPUT and isinstance(puzzle_input, str):
+            puzzle_input = normalize_input(puzzle_input)
+        return self.parse(puzzle_input)
+
     def solve(self, puzzle_input, /, parts: str = "ab"):
         """Solve the puzzle for the given input."""
-        data = self.parse(puzzle_input)
+        return self.solve_parsed(self.prepare(puzzle_input), parts=parts)
+
+    def solve_parsed(self, data, /, parts: str = "ab"):
+        """Solve the puzzle for input that `prepare` already parsed."""
         solution1 = self.part1(data) if "a" in parts else None
         solution2 = self.part2(data) if "b" in parts else None
 
@@ -0,0 +1,112 @@
+"""Check solutions against known answers, run as `python -m aoc_solvings selftest`.
+
+Usage:
+    aoc_solvings selftest [<year> [<day>]] [--golden]
+
+Options:
+    --golden    Also check the answers saved in `data/answers.json` for the
+                inputs saved in `data`.
+
+Every day module with an `EXAMPLE` is solved with `EXAMPLE_ARGS` and checked
+against its `EXAMPLE_ANSWERS`, reporting each part that has an answer. Days given
+to `aoc_solvings.register` are checked in place of the built in ones. Exits with
+status 1 if any part is wrong or raises. Brute force examples take a while, give a
+year and day to check only those.
+"""
+# Standard Library
+from collections.abc import Iterator
+import importlib
+import json
+from pathlib import Path
+import sys
+
+# External Party
+from docopt import docopt
+
+# My Modules
+from aoc_solvings import Day
+from aoc_solvings import load_day
+
+ROOT = Path(__file__).resolve().parent
+DATA = ROOT / "data"
+
+
+def check(solver: Day, puzzle_input: str, expected: tuple) -> dict[str, str | None]:
+    """Return what went wrong with each part that has an expected answer.
+
+    Parts are solved one at a time, so one failing part doesn't hide the other.
+
+    Args:
+        solver (Day): day to run
+        puzzle_input (str): input to solve
+        expected (tuple): part 1 and part 2 answers, None for a part to skip
+
+    Returns:
+        dict[str, str | None]: part to its problem, None when the answer is right
+    """
+    problems: dict[str, str | None] = {}
+    for index, (part, want) in enumerate(zip("ab", expected, strict=True)):
+        if want is None:
+            continue
+        try:
+            answer = solver.solve(puzzle_input, parts=part)[index]
+        except Exception as err:
+            problems[part] = f"raised {err!r}"
+            continue
+        problems[part] = (
+            None if answer == want else f"expected {want!r}, got {answer!r}"
+        )
+    return problems
+
+
+def example_cases(year: int | None = None, day: int | None = None) -> Iterator:
+    """Yield `(year, day, solver, puzzle_input, expected)` for each day's example."""
+    keys = sorted(
+        (
+            int(path.parent.name.removeprefix("src_")),
+            int(path.stem.removeprefix("day")),
+        )
+        for path in ROOT.glob(f"src_{year or '*'}/day{day or '*'}.py")
+    )
+    for key in keys:
+        module = importlib.import_module(f"src_{key[0]}.day{key[1]}")
+        if not hasattr(module, "EXAMPLE_ANSWERS"):
+            continue
+        solver = load_day(*key, *getattr(module, "EXAMPLE_ARGS", ()))
+        yield *key, solver, module.EXAMPLE, module.EXAMPLE_ANSWERS
+
+
+def golden_cases(year: int | None = None, day: int | None = None) -> Iterator:
+    """Yield `(year, day, solver, puzzle_input, expected)` for each saved answer."""
+    manifest = DATA / "answers.json"
+    if not manifest.exists():
+        return
+    for year_key, days in json.loads(manifest.read_text()).items():
+        for day_key, expected in days.items():
+            key = int(year_key), int(day_key)
+            if (year and key[0] != year) or (day and key[1] != day):
+                continue
+            puzzle_input = DATA / f"input{key[0]}-{key[1]}.txt"
+            if puzzle_input.exists():
+                yield *key, load_day(*key), puzzle_input.read_text(), tuple(expected)
+
+
+def main(argv: list[str]) -> None:
+    """Parse `selftest` arguments, report every checked part and set the exit code."""
+    args = docopt(__doc__, argv=["selftest", *argv])
+    year = int(args["<year>"]) if args["<year>"] else None
+    day = int(args["<day>"]) if args["<day>"] else None
+    sources = {"example": example_cases(year, day)}
+    if args["--golden"]:
+        sources["golden"] = golden_cases(year, day)
+    passed = failed = 0
+    for source, cases in sources.items():
+        for case_year, case_day, solver, puzzle_input, expected in cases:
+            for part, problem in check(solver, puzzle_input, expected).items():
+                name = f"{case_year} day {case_day:>2} part {part} {source}"
+                print(f"{name}: {'ok' if problem is None else problem}")
+                passed += problem is None
+                failed += problem is not None
+    print(f"{passed} passed, {failed} failed")
+    if failed:
+        sys.exit(1)
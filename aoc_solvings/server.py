@@ -0,0 +1,156 @@
+"""Serve the solutions over HTTP, started with `python -m aoc_solvings serve`.
+
+Usage:
+    aoc_solvings serve [--host=<host>] [--port=<port>]
+
+Options:
+    --host HOST     Address to listen on. [default: 127.0.0.1]
+    --port PORT     Port to listen on. [default: 8000]
+
+`POST /solve/<year>/<day>` with the raw puzzle input as the body answers with
+JSON `{"part1": ..., "part2": ..., "elapsed": seconds}`. Add `?parts=a` or
+`?parts=b` to solve only one part. Requests with other parts, or without a
+Content-Length giving the size of the input, get a 400.
+
+The server keeps the parsed form of recent inputs, so posting the same input
+again while iterating on a part skips reading and parsing it.
+"""
+# Standard Library
+from collections import OrderedDict
+import copy
+import hashlib
+from http import HTTPStatus
+from http.server import BaseHTTPRequestHandler
+from http.server import ThreadingHTTPServer
+import json
+import logging
+import re
+import threading
+import time
+from urllib.parse import parse_qs
+from urllib.parse import urlsplit
+
+# External Party
+from docopt import docopt
+
+# My Modules
+from aoc_solvings import Day
+from aoc_solvings import has_solution
+from aoc_solvings import load_day
+
+LOG_NAME = "server"
+LOG = logging.getLogger(LOG_NAME)
+
+SOLVE_PATH = re.compile(r"/solve/(\d+)/(\d+)/?")
+PARTS = ("a", "b", "ab")
+# parsed inputs kept warm, oldest dropped first past the limit
+WARM_LIMIT = 32
+
+_warm: OrderedDict[tuple[type[Day], str], object] = OrderedDict()
+_warm_lock = threading.Lock()
+
+
+def solve_warm(
+    year: int, day: int, input_text: str, /, parts: str = "ab"
+) -> tuple[object, object]:
+    """Return answers like `aoc_solvings.solve`, reusing parses of recent inputs.
+
+    Days that change their data get a deep copy of the kept parse, so one request
+    doesn't leak into later or concurrent ones.
+
+    Args:
+        year (int): event year
+        day (int): day of the event
+        input_text (str): puzzle input
+        parts (str, optional): which parts to solve. Defaults to "ab".
+
+    Returns:
+        tuple[object, object]: part 1 and part 2 answers, None for a skipped part
+    """
+    solver = load_day(year, day)
+    key = type(solver), hashlib.sha256(input_text.encode()).hexdigest()
+    with _warm_lock:
+        warm = key in _warm
+        if warm:
+            _warm.move_to_end(key)
+            data = _warm[key]
+    if not warm:
+        data = solver.prepare(input_text)
+        with _warm_lock:
+            _warm[key] = data
+            while len(_warm) > WARM_LIMIT:
+                _warm.popitem(last=False)
+    LOG.info("%s parse for %d day %d", "reused" if warm else "new", year, day)
+    if solver.CHANGES_DATA:
+        data = copy.deepcopy(data)
+    return solver.solve_parsed(data, parts=parts)
+
+
+class SolveHandler(BaseHTTPRequestHandler):
+    """Answer `POST /solve/<year>/<day>` requests."""
+
+    def _reply(self, status: HTTPStatus, body: dict) -> None:
+        payload = json.dumps(body, default=str).encode()
+        self.send_response(status)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(payload)))
+        self.end_headers()
+        self.wfile.write(payload)
+
+    def do_POST(self) -> None:  # noqa: N802
+        """Solve the day named in the path for the input in the body."""
+        url = urlsplit(self.path)
+        if not (match := SOLVE_PATH.fullmatch(url.path)):
+            self._reply(HTTPStatus.NOT_FOUND, {"error": "expected /solve/<year>/<day>"})
+            return
+        year, day = map(int, match.groups())
+        if not has_solution(year, day):
+            error = f"no solution for {year} day {day}"
+            self._reply(HTTPStatus.NOT_FOUND, {"error": error})
+            return
+        parts = parse_qs(url.query).get("parts", ["ab"])[0]
+        if parts not in PARTS:
+            error = f"parts must be one of {', '.join(PARTS)}, got {parts!r}"
+            self._reply(HTTPStatus.BAD_REQUEST, {"error": error})
+            return
+        length = self.headers.get("Content-Length", "")
+        if not length.isdecimal():
+            error = f"expected Content-Length of the puzzle input, got {length!r}"
+            self._reply(HTTPStatus.BAD_REQUEST, {"error": error})
+            return
+        try:
+            puzzle_input = self.rfile.read(int(length)).decode()
+        except UnicodeDecodeError:
+            self._reply(HTTPStatus.BAD_REQUEST, {"error": "puzzle input isn't UTF-8"})
+            return
+        start = time.perf_counter()
+        try:
+            part1, part2 = solve_warm(year, day, puzzle_input, parts=parts)
+        except Exception as err:
+            LOG.exception("solving %d day %d failed", year, day)
+            self._reply(HTTPStatus.UNPROCESSABLE_ENTITY, {"error": repr(err)})
+            return
+        elapsed = time.perf_counter() - start
+        self._reply(HTTPStatus.OK, {"part1": part1, "part2": part2, "elapsed": elapsed})
+
+
+def serve(host: str = "127.0.0.1", port: int = 8000) -> None:
+    """Serve solutions until interrupted.
+
+    Args:
+        host (str, optional): address to listen on. Defaults to "127.0.0.1".
+        port (int, optional): port to listen on. Defaults to 8000.
+    """
+    with ThreadingHTTPServer((host, port), SolveHandler) as server:
+        LOG.info("serving solutions on %s:%d", host, port)
+        try:
+            server.serve_forever()
+        except KeyboardInterrupt:
+            pass
+
+
+def main(argv: list[str]) -> None:
+    """Parse `serve` arguments and start the server."""
+    args = docopt(__doc__, argv=["serve", *argv])
+    logging.basicConfig(level=logging.INFO)
+    serve(args["--host"], int(args["--port"]))
@@ -0,0 +1,110 @@
+"""Advent of Code 2015 Day1 problem.
+
+Usage:
+    day1.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+from itertools import accumulate
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day1"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "()())"
+EXAMPLE_ANSWERS = (-1, 5)
+
+
+class Day1(Day):
+    """Day 1 of Advent of Code 2015."""
+
+    def parse(self, puzzle_input: str) -> list[int]:
+        """Return floor change of each instruction, up is 1 and down is -1."""
+        return [1 if ch == "(" else -1 for ch in puzzle_input.strip()]
+
+    def part1(self, data: list[int]) -> int:
+        """Return floor Santa ends up on.
+
+        Args:
+            data (list[int]): floor changes
+
+        Returns:
+            int
+        """
+        return sum(data)
+
+    def part2(self, data: list[int]) -> int:
+        """Return position of the first instruction that enters the basement.
+
+        Args:
+            data (list[int]): floor changes
+
+        Raises:
+            AnswerNotFoundError: if Santa never goes below floor 0
+
+        Returns:
+            int
+        """
+        for position, floor in enumerate(accumulate(data), start=1):
+            if floor < 0:
+                LOG.info("reached basement at instruction %d", position)
+                return position
+        raise AnswerNotFoundError()
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 1, 2015
+    day = Day1()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
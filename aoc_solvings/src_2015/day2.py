@@ -0,0 +1,120 @@
+"""Advent of Code 2015 Day2 problem.
+
+Usage:
+    day2.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day2"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    2x3x4
+    1x1x10"""
+)
+EXAMPLE_ANSWERS = (101, 48)
+
+# present dimensions sorted smallest first
+Present = tuple[int, int, int]
+
+
+class Day2(Day):
+    """Day 2 of Advent of Code 2015."""
+
+    def parse(self, puzzle_input: str) -> list[Present]:
+        """Return each present's dimensions, sorted so the smallest side is first."""
+        return [
+            tuple(sorted(map(int, line.split("x"))))  # type: ignore[misc]
+            for line in puzzle_input.splitlines()
+        ]
+
+    def part1(self, data: list[Present]) -> int:
+        """Return square feet of wrapping paper needed.
+
+        Each present needs its surface area plus the area of its smallest side.
+
+        Args:
+            data (list[Present]): present dimensions
+
+        Returns:
+            int
+        """
+        return sum(
+            2 * (small * mid + mid * large + small * large) + small * mid
+            for small, mid, large in data
+        )
+
+    def part2(self, data: list[Present]) -> int:
+        """Return feet of ribbon needed.
+
+        Each present needs its smallest perimeter plus its volume for the bow.
+
+        Args:
+            data (list[Present]): present dimensions
+
+        Returns:
+            int
+        """
+        return sum(
+            2 * (small + mid) + small * mid * large for small, mid, large in data
+        )
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 2, 2015
+    day = Day2()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,116 @@
+"""Advent of Code 2015 Day3 problem.
+
+Usage:
+    day3.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.template import Day
+
+LOG_NAME = "day3"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "^v^v^v^v^v"
+EXAMPLE_ANSWERS = (2, 11)
+
+MOVES = {"^": (0, 1), "v": (0, -1), ">": (1, 0), "<": (-1, 0)}
+
+
+class Day3(Day):
+    """Day 3 of Advent of Code 2015."""
+
+    def parse(self, puzzle_input: str) -> list[TwoDimPoint]:
+        """Return the step each direction moves."""
+        return [MOVES[ch] for ch in puzzle_input.strip()]
+
+    @staticmethod
+    def visited(moves: list[TwoDimPoint]) -> set[TwoDimPoint]:
+        """Return every house visited following moves from the origin."""
+        x = y = 0
+        houses = {(x, y)}
+        for dx, dy in moves:
+            x, y = x + dx, y + dy
+            houses.add((x, y))
+        return houses
+
+    def part1(self, data: list[TwoDimPoint]) -> int:
+        """Return number of houses that get at least one present.
+
+        Args:
+            data (list[TwoDimPoint]): moves
+
+        Returns:
+            int
+        """
+        return len(self.visited(data))
+
+    def part2(self, data: list[TwoDimPoint]) -> int:
+        """Return number of houses visited when Santa and Robo-Santa alternate moves.
+
+        Args:
+            data (list[TwoDimPoint]): moves
+
+        Returns:
+            int
+        """
+        houses = self.visited(data[::2]) | self.visited(data[1::2])
+        LOG.info("%d houses visited between both Santas", len(houses))
+        return len(houses)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 3, 2015
+    day = Day3()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,116 @@
+"""Advent of Code 2015 Day4 problem.
+
+Usage:
+    day4.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.hashing import parallel_chunks
+    from common.hashing import prefixed_hashes
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.hashing import parallel_chunks
+    from common.hashing import prefixed_hashes
+    from common.template import Day
+
+LOG_NAME = "day4"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "abcdef"
+EXAMPLE_ANSWERS = (609043, None)
+
+
+class Day4(Day):
+    """Day 4 of Advent of Code 2015."""
+
+    def parse(self, puzzle_input: str) -> str:
+        """Return the secret key."""
+        return puzzle_input.strip()
+
+    @staticmethod
+    def mine(secret: str, zeroes: int) -> int:
+        """Return lowest positive number whose hash with secret has leading zeroes."""
+        chunks = parallel_chunks(prefixed_hashes, secret, "0" * zeroes, start=1)
+        found = next(chunk for chunk in chunks if chunk)
+        chunks.close()
+        index, digest = found[0]
+        LOG.info("mined %d giving hash %s", index, digest)
+        return index
+
+    def part1(self, data: str) -> int:
+        """Return lowest number giving an AdventCoin hash with five leading zeroes.
+
+        Args:
+            data (str): secret key
+
+        Returns:
+            int
+        """
+        return self.mine(data, 5)
+
+    def part2(self, data: str) -> int:
+        """Return lowest number giving an AdventCoin hash with six leading zeroes.
+
+        Args:
+            data (str): secret key
+
+        Returns:
+            int
+        """
+        return self.mine(data, 6)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 4, 2015
+    day = Day4()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # example only covers five leading zeroes
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,173 @@
+"""Assembunny interpreter shared by 2016 days 12, 23 and 25."""
+from __future__ import annotations
+
+# Standard Library
+from dataclasses import dataclass
+import logging
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+LOG = logging.getLogger("assembunny")
+
+REGISTERS = "abcd"
+Operand = str | int
+
+# toggling swaps one argument ops to inc/dec and two argument ops to jnz/cpy
+_TOGGLED_UNARY = {"inc": "dec"}
+_TOGGLED_BINARY = {"jnz": "cpy"}
+# the multiply loop needs distinct target, inner and outer counter registers
+_MULTIPLY_REGISTERS = 3
+
+
+class InvalidAssembunnyError(Exception):
+    """Error for when a line isn't an assembunny instruction."""
+
+    def __init__(self, line: str, *args: object) -> None:
+        """Error message shows the line that couldn't be parsed."""
+        super().__init__(f"Unknown assembunny instruction {line!r}", *args)
+
+
+@dataclass(slots=True)
+class Instruction:
+    """Single assembunny operation and its operands."""
+
+    op: str
+    args: tuple[Operand, ...]
+
+    @classmethod
+    def from_str(cls, line: str) -> Instruction:
+        """Parse a line like `cpy 41 a`, numeric operands become ints."""
+        op, *args = line.split()
+        if op not in {"cpy", "inc", "dec", "jnz", "tgl", "out"}:
+            raise InvalidAssembunnyError(line)
+        return cls(op, tuple(arg if arg in REGISTERS else int(arg) for arg in args))
+
+    def toggled(self) -> Instruction:
+        """Return the instruction that `tgl` turns this one into."""
+        if len(self.args) == 1:
+            return Instruction(_TOGGLED_UNARY.get(self.op, "inc"), self.args)
+        return Instruction(_TOGGLED_BINARY.get(self.op, "jnz"), self.args)
+
+
+def parse_program(puzzle_input: str) -> list[Instruction]:
+    """Return each line of assembunny source as an Instruction."""
+    return [Instruction.from_str(line) for line in puzzle_input.splitlines()]
+
+
+class Assembunny:
+    """Virtual machine running an assembunny program on four registers."""
+
+    def __init__(self, program: list[Instruction], **registers: int) -> None:
+        """Copy program, so `tgl` can't leak, and set initial register values."""
+        self.program = list(program)
+        self.registers = dict.fromkeys(REGISTERS, 0) | registers
+        self.pc = 0
+
+    def _value(self, operand: Operand) -> int:
+        return self.registers[operand] if isinstance(operand, str) else operand
+
+    def _matches(self, offset: int, op: str, *args: Operand) -> bool:
+        index = self.pc + offset
+        if not 0 <= index < len(self.program):
+            return False
+        return self.program[index] == Instruction(op, args)
+
+    def _try_multiply(self) -> bool:
+        """Collapse a nested increment loop into a multiplication.
+
+        Pattern is `cpy x c / inc a / dec c / jnz c -2 / dec d / jnz d -5`,
+        which adds `x * d` to `a` and leaves both counters at zero.
+        """
+        cpy = self.program[self.pc]
+        if cpy.op != "cpy" or self.pc + 5 >= len(self.program):
+            return False
+        source, inner = cpy.args
+        target = self.program[self.pc + 1].args[0]
+        outer = self.program[self.pc + 4].args[0]
+        registers = {target, inner, outer}
+        if not (
+            len(registers) == _MULTIPLY_REGISTERS
+            and all(isinstance(register, str) for register in registers)
+            and source not in registers
+            and self._matches(1, "inc", target)
+            and self._matches(2, "dec", inner)
+            and self._matches(3, "jnz", inner, -2)
+            and self._matches(4, "dec", outer)
+            and self._matches(5, "jnz", outer, -5)
+        ):
+            return False
+        LOG.debug("multiplying %s * %s into %s at %d", source, outer, target, self.pc)
+        self.registers[target] += self._value(source) * self.registers[outer]
+        self.registers[inner] = self.registers[outer] = 0
+        self.pc += 6
+        return True
+
+    def _try_add(self) -> bool:
+        """Collapse `inc a / dec c / jnz c -2` into `a += c`."""
+        inc = self.program[self.pc]
+        if inc.op != "inc" or self.pc + 2 >= len(self.program):
+            return False
+        target = inc.args[0]
+        counter = self.program[self.pc + 1].args[0]
+        if not (
+            isinstance(target, str)
+            and isinstance(counter, str)
+            and target != counter
+            and self._matches(1, "dec", counter)
+            and self._matches(2, "jnz", counter, -2)
+        ):
+            return False
+        self.registers[target] += self.registers[counter]
+        self.registers[counter] = 0
+        self.pc += 3
+        return True
+
+    def _step(self) -> int | None:
+        """Execute instruction at the program counter, return any output."""
+        if self._try_multiply() or self._try_add():
+            return None
+        instruction = self.program[self.pc]
+        args = instruction.args
+        output = None
+        match instruction.op:
+            case "cpy" if isinstance(args[1], str):
+                self.registers[args[1]] = self._value(args[0])
+            case "inc" if isinstance(args[0], str):
+                self.registers[args[0]] += 1
+            case "dec" if isinstance(args[0], str):
+                self.registers[args[0]] -= 1
+            case "jnz":
+                if self._value(args[0]) != 0:
+                    self.pc += self._value(args[1])
+                    return None
+            case "tgl":
+                target = self.pc + self._value(args[0])
+                if 0 <= target < len(self.program):
+                    self.program[target] = self.program[target].toggled()
+                    LOG.debug("toggled %d to %s", target, self.program[target])
+            case "out":
+                output = self._value(args[0])
+            case _:
+                # toggling can create invalid instructions, those are skipped
+                LOG.debug("skipping %s at %d", instruction, self.pc)
+        self.pc += 1
+        return output
+
+    def execute(self) -> Iterator[int]:
+        """Run until the program counter leaves the program, yield `out` values."""
+        while 0 <= self.pc < len(self.program):
+            if (output := self._step()) is not None:
+                yield output
+
+    def run(self) -> dict[str, int]:
+        """Run program to completion, ignoring output, and return the registers."""
+        for _ in self.execute():
+            pass
+        return self.registers
+
+    @property
+    def state(self) -> tuple[int, ...]:
+        """Return program counter and registers, hashable for loop detection."""
+        return (self.pc, *self.registers.values())
@@ -0,0 +1,146 @@
+"""Advent of Code 2016 Day1 problem.
+
+Usage:
+    day1.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.grid import segment_intersection
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.grid import segment_intersection
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day1"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "R8, R4, R4, R8"
+EXAMPLE_ANSWERS = (8, 4)
+
+Segment = tuple[TwoDimPoint, TwoDimPoint]
+# north, east, south, west - turning right steps forward through the list
+HEADINGS = [(0, 1), (1, 0), (0, -1), (-1, 0)]
+
+
+class Day1(Day):
+    """Day 1 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> list[Segment]:
+        """Return each straight segment walked, starting at the origin facing north."""
+        x = y = heading = 0
+        segments = []
+        for step in puzzle_input.strip().split(", "):
+            heading = (heading + (1 if step[0] == "R" else -1)) % len(HEADINGS)
+            dx, dy = HEADINGS[heading]
+            blocks = int(step[1:])
+            segments.append(((x, y), (x + dx * blocks, y + dy * blocks)))
+            x, y = segments[-1][1]
+        return segments
+
+    def part1(self, data: list[Segment]) -> int:
+        """Return blocks away Easter Bunny HQ is at the end of the directions.
+
+        Args:
+            data (list[Segment]): segments walked
+
+        Returns:
+            int
+        """
+        x, y = data[-1][1]
+        return abs(x) + abs(y)
+
+    def part2(self, data: list[Segment]) -> int:
+        """Return blocks away the first location visited twice is.
+
+        Each segment is tested from its first new block against every earlier
+        segment, the crossing nearest its start wins. Zero block steps only turn,
+        so they visit nothing new.
+
+        Args:
+            data (list[Segment]): segments walked
+
+        Raises:
+            AnswerNotFoundError: if the path never crosses itself
+
+        Returns:
+            int
+        """
+        for index, (start, end) in enumerate(data):
+            blocks = abs(end[0] - start[0]) + abs(end[1] - start[1])
+            if not blocks:
+                continue
+            # step off the start, it is the end of the previous segment
+            first = (
+                start[0] + (end[0] - start[0]) // blocks,
+                start[1] + (end[1] - start[1]) // blocks,
+            )
+            crossings = [
+                point
+                for earlier in data[:index]
+                if (point := segment_intersection(first, end, *earlier)) is not None
+            ]
+            if crossings:
+                x, y = min(
+                    crossings, key=lambda p: abs(p[0] - start[0]) + abs(p[1] - start[1])
+                )
+                LOG.info("first revisited %s on segment %d", (x, y), index)
+                return abs(x) + abs(y)
+        raise AnswerNotFoundError()
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 1, 2016
+    day = Day1()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
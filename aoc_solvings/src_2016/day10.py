@@ -0,0 +1,196 @@
+"""Advent of Code 2016 Day10 problem.
+
+Usage:
+    day10.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+from collections import defaultdict
+from collections import deque
+from dataclasses import dataclass
+from dataclasses import field
+import logging
+from math import prod
+import os
+from pathlib import Path
+import re
+import sys
+from textwrap import dedent
+from typing import NamedTuple
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day10"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    value 5 goes to bot 2
+    bot 2 gives low to bot 1 and high to bot 0
+    value 3 goes to bot 1
+    bot 1 gives low to output 1 and high to bot 0
+    bot 0 gives low to output 2 and high to output 0
+    value 2 goes to bot 2"""
+)
+# example asks for the bot comparing chips 2 and 5
+EXAMPLE_ARGS = 2, 5
+EXAMPLE_ANSWERS = (2, 30)
+# a bot acts once it holds this many chips
+CHIPS_PER_BOT = 2
+
+VALUE_LINE = re.compile(r"value (\d+) goes to bot (\d+)")
+BOT_LINE = re.compile(
+    r"bot (\d+) gives low to (bot|output) (\d+) and high to (bot|output) (\d+)"
+)
+
+
+class Destination(NamedTuple):
+    """Where a bot hands a chip to, either another bot or an output bin."""
+
+    kind: str
+    number: int
+
+
+@dataclass(slots=True)
+class Factory:
+    """Bot instructions plus the chips each bot starts holding."""
+
+    rules: dict[int, tuple[Destination, Destination]] = field(default_factory=dict)
+    holding: defaultdict[int, list[int]] = field(
+        default_factory=lambda: defaultdict(list)
+    )
+
+
+@dataclass(slots=True)
+class FactoryRun:
+    """Result of letting every bot hand off its chips."""
+
+    comparisons: dict[tuple[int, int], int] = field(default_factory=dict)
+    outputs: dict[int, int] = field(default_factory=dict)
+
+
+class Day10(Day):
+    """Day 10 of Advent of Code 2016."""
+
+    def __init__(self, low: int = 17, high: int = 61) -> None:
+        """Set the pair of chips whose comparing bot part 1 asks for."""
+        self.chips = low, high
+
+    def parse(self, puzzle_input: str) -> FactoryRun:
+        """Parse the bot instructions and run the factory until it is idle."""
+        factory = Factory()
+        for line in puzzle_input.splitlines():
+            if match := VALUE_LINE.fullmatch(line):
+                factory.holding[int(match[2])].append(int(match[1]))
+            elif match := BOT_LINE.fullmatch(line):
+                factory.rules[int(match[1])] = (
+                    Destination(match[2], int(match[3])),
+                    Destination(match[4], int(match[5])),
+                )
+        return self.run_factory(factory)
+
+    @staticmethod
+    def run_factory(factory: Factory) -> FactoryRun:
+        """Hand chips off from every bot holding two until none are left.
+
+        Bots ready to act are kept in a work queue, a bot joins it the moment
+        it receives its second chip. The factory's held chips are consumed.
+        """
+        holding = factory.holding
+        result = FactoryRun()
+        ready = deque(
+            bot for bot, chips in holding.items() if len(chips) == CHIPS_PER_BOT
+        )
+        while ready:
+            bot = ready.popleft()
+            low, high = sorted(holding.pop(bot))
+            result.comparisons[(low, high)] = bot
+            LOG.debug("bot %d compares %d and %d", bot, low, high)
+            for chip, dest in zip((low, high), factory.rules[bot], strict=True):
+                if dest.kind == "output":
+                    result.outputs[dest.number] = chip
+                    continue
+                holding[dest.number].append(chip)
+                if len(holding[dest.number]) == CHIPS_PER_BOT:
+                    ready.append(dest.number)
+        return result
+
+    def part1(self, data: FactoryRun) -> int:
+        """Return number of the bot that compares the two interesting chips.
+
+        Args:
+            data (FactoryRun): comparisons and outputs of the idle factory
+
+        Raises:
+            AnswerNotFoundError: if no bot ever compares those chips
+
+        Returns:
+            int
+        """
+        if self.chips not in data.comparisons:
+            raise AnswerNotFoundError()
+        return data.comparisons[self.chips]
+
+    def part2(self, data: FactoryRun) -> int:
+        """Return product of the chip values in outputs 0, 1 and 2.
+
+        Args:
+            data (FactoryRun): comparisons and outputs of the idle factory
+
+        Returns:
+            int
+        """
+        return prod(data.outputs[output] for output in range(3))
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 10, 2016
+    day = Day10(*EXAMPLE_ARGS) if args["--example"] else Day10()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,201 @@
+"""Advent of Code 2016 Day11 problem.
+
+Usage:
+    day11.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+from itertools import combinations
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+from textwrap import dedent
+from typing import TYPE_CHECKING
+from typing import NamedTuple
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.search import bfs_distance
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.search import bfs_distance
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day11"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+    The second floor contains a hydrogen generator.
+    The third floor contains a lithium generator.
+    The fourth floor contains nothing relevant."""
+)
+EXAMPLE_ANSWERS = (11, None)
+
+GENERATOR = re.compile(r"(\w+) generator")
+MICROCHIP = re.compile(r"(\w+)-compatible microchip")
+TOP_FLOOR = 3
+
+# floor of an element's generator and floor of its microchip
+Pair = tuple[int, int]
+
+
+class Facility(NamedTuple):
+    """Elevator floor and the floors of every generator/microchip pair.
+
+    Elements are interchangeable, so pairs are kept sorted and two facilities
+    that only differ in which element is where compare equal.
+    """
+
+    elevator: int
+    pairs: tuple[Pair, ...]
+
+    @classmethod
+    def canonical(cls, elevator: int, pairs: list[Pair]) -> Facility:
+        """Return the facility with its pairs in sorted order."""
+        return cls(elevator, tuple(sorted(pairs)))
+
+    def is_safe(self) -> bool:
+        """Return whether no microchip shares a floor with a foreign generator.
+
+        A chip is safe when its own generator is on the same floor.
+        """
+        generator_floors = {generator for generator, _ in self.pairs}
+        return all(
+            generator == chip or chip not in generator_floors
+            for generator, chip in self.pairs
+        )
+
+    def is_done(self) -> bool:
+        """Return whether everything is on the top floor."""
+        return all(floor == TOP_FLOOR for pair in self.pairs for floor in pair)
+
+    def next_states(self) -> Iterator[Facility]:
+        """Yield every safe facility reachable with one elevator ride."""
+        items = [floor for pair in self.pairs for floor in pair]
+        here = [index for index, floor in enumerate(items) if floor == self.elevator]
+        lowest = min(items)
+        for direction in (1, -1):
+            new_floor = self.elevator + direction
+            # nothing would ever need to go back down below the lowest item
+            if not lowest <= new_floor <= TOP_FLOOR:
+                continue
+            for count in (1, 2):
+                for carried in combinations(here, count):
+                    moved = items.copy()
+                    for index in carried:
+                        moved[index] = new_floor
+                    state = self.canonical(
+                        new_floor, list(zip(moved[::2], moved[1::2], strict=True))
+                    )
+                    if state.is_safe():
+                        yield state
+
+
+class Day11(Day):
+    """Day 11 of Advent of Code 2016."""
+
+    EXTRA_ELEMENTS = ("elerium", "dilithium")
+
+    def parse(self, puzzle_input: str) -> dict[str, Pair]:
+        """Return floor of the generator and microchip for each element."""
+        generators, chips = {}, {}
+        for floor, line in enumerate(puzzle_input.splitlines()):
+            generators |= dict.fromkeys(GENERATOR.findall(line), floor)
+            chips |= dict.fromkeys(MICROCHIP.findall(line), floor)
+        return {element: (generators[element], chips[element]) for element in chips}
+
+    @staticmethod
+    def fewest_steps(elements: dict[str, Pair]) -> int:
+        """Return fewest elevator rides to bring everything to the top floor.
+
+        Raises:
+            AnswerNotFoundError: if the top floor can't be reached safely
+        """
+        start = Facility.canonical(0, list(elements.values()))
+        LOG.info("starting facility %s", start)
+        steps = bfs_distance(start, Facility.next_states, Facility.is_done)
+        if steps is None:
+            raise AnswerNotFoundError()
+        return steps
+
+    def part1(self, data: dict[str, Pair]) -> int:
+        """Return fewest steps to bring all generators and microchips to the top.
+
+        Args:
+            data (dict[str, Pair]): element to floors of its generator and chip
+
+        Returns:
+            int
+        """
+        return self.fewest_steps(data)
+
+    def part2(self, data: dict[str, Pair]) -> int:
+        """Return fewest steps with an elerium and dilithium pair on the first floor.
+
+        Args:
+            data (dict[str, Pair]): element to floors of its generator and chip
+
+        Returns:
+            int
+        """
+        return self.fewest_steps(data | dict.fromkeys(self.EXTRA_ELEMENTS, (0, 0)))
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 11, 2016
+    day = Day11()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # extra pairs make the example facility unsafe from the start
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
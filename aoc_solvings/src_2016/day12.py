@@ -0,0 +1,119 @@
+"""Advent of Code 2016 Day12 problem.
+
+Usage:
+    day12.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+    from src_2016.assembunny import Assembunny
+    from src_2016.assembunny import Instruction
+    from src_2016.assembunny import parse_program
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+    from src_2016.assembunny import Assembunny
+    from src_2016.assembunny import Instruction
+    from src_2016.assembunny import parse_program
+
+LOG_NAME = "day12"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    cpy 41 a
+    inc a
+    inc a
+    dec a
+    jnz a 2
+    dec a"""
+)
+EXAMPLE_ANSWERS = (42, 42)
+
+
+class Day12(Day):
+    """Day 12 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> list[Instruction]:
+        """Return the assembunny program."""
+        return parse_program(puzzle_input)
+
+    def part1(self, data: list[Instruction]) -> int:
+        """Return value left in register a with every register starting at 0.
+
+        Args:
+            data (list[Instruction]): assembunny program
+
+        Returns:
+            int
+        """
+        registers = Assembunny(data).run()
+        LOG.info("registers after running %s", registers)
+        return registers["a"]
+
+    def part2(self, data: list[Instruction]) -> int:
+        """Return value left in register a when register c starts at 1.
+
+        Args:
+            data (list[Instruction]): assembunny program
+
+        Returns:
+            int
+        """
+        registers = Assembunny(data, c=1).run()
+        LOG.info("registers after running %s", registers)
+        return registers["a"]
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 12, 2016
+    day = Day12()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
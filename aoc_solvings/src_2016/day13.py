@@ -0,0 +1,154 @@
+"""Advent of Code 2016 Day13 problem.
+
+Usage:
+    day13.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+from functools import partial
+from itertools import pairwise
+import logging
+import os
+from pathlib import Path
+import sys
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.search import bfs
+    from common.search import bfs_distance
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.search import bfs
+    from common.search import bfs_distance
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day13"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "10"
+# example target is the cubicle at 7,4
+EXAMPLE_ARGS = ((7, 4),)
+EXAMPLE_ANSWERS = (11, None)
+
+START: TwoDimPoint = 1, 1
+
+
+def is_open(favorite: int, x: int, y: int) -> bool:
+    """Return whether (x, y) is open space rather than a wall."""
+    if x < 0 or y < 0:
+        return False
+    return (x * x + 3 * x + 2 * x * y + y + y * y + favorite).bit_count() % 2 == 0
+
+
+def open_neighbors(favorite: int, pos: TwoDimPoint) -> Iterator[TwoDimPoint]:
+    """Yield open cells one step up, down, left or right of pos."""
+    for dx, dy in pairwise((0, 1, 0, -1, 0)):
+        if is_open(favorite, new_x := pos[0] + dx, new_y := pos[1] + dy):
+            yield new_x, new_y
+
+
+class Day13(Day):
+    """Day 13 of Advent of Code 2016."""
+
+    MAX_STEPS = 50
+
+    def __init__(self, target: TwoDimPoint = (31, 39)) -> None:
+        """Set the cubicle part 1 is trying to reach."""
+        self.target = target
+
+    def parse(self, puzzle_input: str) -> int:
+        """Return the office designer's favorite number."""
+        return int(puzzle_input)
+
+    def part1(self, data: int) -> int:
+        """Return fewest steps from (1, 1) to the target cubicle.
+
+        Args:
+            data (int): favorite number
+
+        Raises:
+            AnswerNotFoundError: if walls block off the target
+
+        Returns:
+            int
+        """
+        steps = bfs_distance(
+            START, partial(open_neighbors, data), lambda pos: pos == self.target
+        )
+        if steps is None:
+            raise AnswerNotFoundError()
+        return steps
+
+    def part2(self, data: int) -> int:
+        """Return number of cells reachable in at most 50 steps.
+
+        Args:
+            data (int): favorite number
+
+        Returns:
+            int
+        """
+        reachable = sum(
+            1 for _ in bfs(START, partial(open_neighbors, data), self.MAX_STEPS)
+        )
+        LOG.info("%d locations within %d steps", reachable, self.MAX_STEPS)
+        return reachable
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 13, 2016
+    day = Day13(*EXAMPLE_ARGS) if args["--example"] else Day13()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # example only defines an answer for the first part
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
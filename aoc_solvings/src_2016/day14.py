@@ -0,0 +1,172 @@
+"""Advent of Code 2016 Day14 problem.
+
+Usage:
+    day14.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+from collections import deque
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.hashing import hash_range
+    from common.hashing import parallel_chunks
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.hashing import hash_range
+    from common.hashing import parallel_chunks
+    from common.template import Day
+
+LOG_NAME = "day14"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "abc"
+EXAMPLE_ANSWERS = (22728, 22551)
+
+TRIPLE = re.compile(r"(.)\1\1")
+
+
+class HashCache:
+    """Rolling window over the hashes of `salt + index`.
+
+    Hashes are computed ahead in parallel chunks and dropped once the search
+    has moved past them, so only the lookahead window stays in memory.
+    """
+
+    CHUNK_SIZE = 1_000
+
+    def __init__(self, salt: str, stretch: int = 0) -> None:
+        """Start computing hashes of the salt from index 0."""
+        self._chunks = parallel_chunks(
+            hash_range, salt, stretch, chunk_size=self.CHUNK_SIZE
+        )
+        self._window: deque[str] = deque()
+        self._offset = 0
+
+    def __getitem__(self, index: int) -> str:
+        """Return hash for `index`, which must not be before the window."""
+        while index - self._offset >= len(self._window):
+            self._window.extend(next(self._chunks))
+        return self._window[index - self._offset]
+
+    def advance_to(self, index: int) -> None:
+        """Drop every cached hash before `index`."""
+        while self._offset < index and self._window:
+            self._window.popleft()
+            self._offset += 1
+
+    def close(self) -> None:
+        """Stop the background hashing workers."""
+        self._chunks.close()
+
+
+class Day14(Day):
+    """Day 14 of Advent of Code 2016."""
+
+    KEYS_NEEDED = 64
+    LOOKAHEAD = 1_000
+    STRETCH = 2016
+
+    @classmethod
+    def find_key_index(cls, salt: str, stretch: int = 0) -> int:
+        """Return index producing the 64th one-time pad key.
+
+        A hash is a key when its first triple character appears five times in a
+        row in any of the next 1000 hashes.
+        """
+        cache = HashCache(salt, stretch)
+        keys_found, index = 0, -1
+        while keys_found < cls.KEYS_NEEDED:
+            index += 1
+            cache.advance_to(index)
+            if (triple := TRIPLE.search(cache[index])) is None:
+                continue
+            quintuple = triple[1] * 5
+            if any(
+                quintuple in cache[later]
+                for later in range(index + 1, index + 1 + cls.LOOKAHEAD)
+            ):
+                keys_found += 1
+                LOG.debug("key %d found at index %d", keys_found, index)
+        cache.close()
+        return index
+
+    def parse(self, puzzle_input: str) -> str:
+        """Return the salt."""
+        return puzzle_input.strip()
+
+    def part1(self, data: str) -> int:
+        """Return index that produces the 64th key.
+
+        Args:
+            data (str): salt
+
+        Returns:
+            int
+        """
+        return self.find_key_index(data)
+
+    def part2(self, data: str) -> int:
+        """Return index that produces the 64th key using stretched hashes.
+
+        Every hash is rehashed an extra 2016 times, which dominates runtime.
+
+        Args:
+            data (str): salt
+
+        Returns:
+            int
+        """
+        return self.find_key_index(data, self.STRETCH)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 14, 2016
+    day = Day14()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
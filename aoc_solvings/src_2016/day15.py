@@ -0,0 +1,144 @@
+"""Advent of Code 2016 Day15 problem.
+
+Usage:
+    day15.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+from textwrap import dedent
+from typing import NamedTuple
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.number_theory import crt
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.number_theory import crt
+    from common.template import Day
+
+LOG_NAME = "day15"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    Disc #1 has 5 positions; at time=0, it is at position 4.
+    Disc #2 has 2 positions; at time=0, it is at position 1."""
+)
+EXAMPLE_ANSWERS = (5, None)
+
+DISC = re.compile(
+    r"Disc #(\d+) has (\d+) positions; at time=0, it is at position (\d+)"
+)
+
+
+class Disc(NamedTuple):
+    """Spinning disc the capsule has to fall through."""
+
+    depth: int
+    positions: int
+    start: int
+
+    def congruence(self) -> tuple[int, int]:
+        """Return residue and modulus of button press times that line this disc up.
+
+        The capsule reaches the disc `depth` seconds after the press and needs the
+        disc to be at position 0 then.
+        """
+        return -(self.start + self.depth) % self.positions, self.positions
+
+
+class Day15(Day):
+    """Day 15 of Advent of Code 2016."""
+
+    EXTRA_DISC_POSITIONS = 11
+
+    def parse(self, puzzle_input: str) -> list[Disc]:
+        """Return each disc's depth, position count and position at time 0."""
+        return [
+            Disc(*map(int, match.groups()))
+            for match in DISC.finditer(puzzle_input)
+        ]
+
+    @staticmethod
+    def first_press(discs: list[Disc]) -> int:
+        """Return the first time the button can be pressed to get the capsule."""
+        time, period = crt(disc.congruence() for disc in discs)
+        LOG.info("capsule falls through at %d, repeating every %d", time, period)
+        return time
+
+    def part1(self, data: list[Disc]) -> int:
+        """Return first time to press the button.
+
+        Args:
+            data (list[Disc]): discs from top to bottom
+
+        Returns:
+            int
+        """
+        return self.first_press(data)
+
+    def part2(self, data: list[Disc]) -> int:
+        """Return first time to press the button with an extra disc at the bottom.
+
+        Args:
+            data (list[Disc]): discs from top to bottom
+
+        Returns:
+            int
+        """
+        extra = Disc(len(data) + 1, self.EXTRA_DISC_POSITIONS, 0)
+        return self.first_press([*data, extra])
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 15, 2016
+    day = Day15()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # example only defines an answer for the first part
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,136 @@
+"""Advent of Code 2016 Day16 problem.
+
+Usage:
+    day16.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day16"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "10000"
+# example disk holds 20 bits
+EXAMPLE_ARGS = (20,)
+EXAMPLE_ANSWERS = ("01100", None)
+
+INVERT = str.maketrans("01", "10")
+
+
+def dragon_fill(data: str, length: int) -> str:
+    """Return first `length` characters of the modified dragon curve of data."""
+    while len(data) < length:
+        data = f"{data}0{data[::-1].translate(INVERT)}"
+    return data[:length]
+
+
+def checksum(data: str) -> str:
+    """Return checksum of data in a single pass.
+
+    Reducing pairs until the length is odd means every checksum character
+    covers a chunk of the largest power of two dividing the length. Each pair
+    reduction is an XNOR, so a chunk reduces to 1 exactly when it holds an even
+    number of ones.
+    """
+    chunk = len(data) & -len(data)
+    if chunk == 1:
+        return data
+    return "".join(
+        "1" if data.count("1", start, start + chunk) % 2 == 0 else "0"
+        for start in range(0, len(data), chunk)
+    )
+
+
+class Day16(Day):
+    """Day 16 of Advent of Code 2016."""
+
+    LARGE_DISK = 35651584
+
+    def __init__(self, disk: int = 272) -> None:
+        """Set the size of the disk filled in part 1."""
+        self.disk = disk
+
+    def parse(self, puzzle_input: str) -> str:
+        """Return the initial state."""
+        return puzzle_input.strip()
+
+    def part1(self, data: str) -> str:
+        """Return checksum of the data filling the first disk.
+
+        Args:
+            data (str): initial state
+
+        Returns:
+            str
+        """
+        return checksum(dragon_fill(data, self.disk))
+
+    def part2(self, data: str) -> str:
+        """Return checksum of the data filling the 35651584 long disk.
+
+        Args:
+            data (str): initial state
+
+        Returns:
+            str
+        """
+        return checksum(dragon_fill(data, self.LARGE_DISK))
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 16, 2016
+    day = Day16(*EXAMPLE_ARGS) if args["--example"] else Day16()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # example only covers filling the small disk
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,165 @@
+"""Advent of Code 2016 Day17 problem.
+
+Usage:
+    day17.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+from functools import partial
+import logging
+import os
+from pathlib import Path
+import sys
+from typing import TYPE_CHECKING
+from typing import NamedTuple
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.hashing import md5_hex
+    from common.search import bfs
+    from common.search import dfs
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.hashing import md5_hex
+    from common.search import bfs
+    from common.search import dfs
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day17"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "ihgpwlah"
+EXAMPLE_ANSWERS = ("DDRRRD", 370)
+
+GRID_SIZE = 4
+OPEN_DOOR = frozenset("bcdef")
+# door order in the hash is up, down, left, right
+MOVES = (("U", 0, -1), ("D", 0, 1), ("L", -1, 0), ("R", 1, 0))
+
+
+class Route(NamedTuple):
+    """Room reached and the moves taken to get there."""
+
+    x: int
+    y: int
+    path: str
+
+    @property
+    def in_vault(self) -> bool:
+        """Return whether this route ends in the bottom right vault."""
+        return self.x == self.y == GRID_SIZE - 1
+
+
+def open_doors(passcode: str, route: Route) -> Iterator[Route]:
+    """Yield routes one room further, the vault is a dead end once reached."""
+    if route.in_vault:
+        return
+    doors = md5_hex(f"{passcode}{route.path}")[:4]
+    for door, (move, dx, dy) in zip(doors, MOVES, strict=True):
+        x, y = route.x + dx, route.y + dy
+        if door in OPEN_DOOR and 0 <= x < GRID_SIZE and 0 <= y < GRID_SIZE:
+            yield Route(x, y, route.path + move)
+
+
+class Day17(Day):
+    """Day 17 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> str:
+        """Return the passcode."""
+        return puzzle_input.strip()
+
+    def part1(self, data: str) -> str:
+        """Return the moves of the shortest path into the vault.
+
+        Args:
+            data (str): passcode
+
+        Raises:
+            AnswerNotFoundError: if every path is closed off
+
+        Returns:
+            str
+        """
+        for route, _ in bfs(Route(0, 0, ""), partial(open_doors, data)):
+            if route.in_vault:
+                return route.path
+        raise AnswerNotFoundError()
+
+    def part2(self, data: str) -> int:
+        """Return length of the longest path into the vault.
+
+        Args:
+            data (str): passcode
+
+        Raises:
+            AnswerNotFoundError: if every path is closed off
+
+        Returns:
+            int
+        """
+        longest = max(
+            (
+                len(route.path)
+                for route in dfs(Route(0, 0, ""), partial(open_doors, data))
+                if route.in_vault
+            ),
+            default=None,
+        )
+        if longest is None:
+            raise AnswerNotFoundError()
+        LOG.info("longest route to the vault is %d steps", longest)
+        return longest
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 17, 2016
+    day = Day17()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,149 @@
+"""Advent of Code 2016 Day18 problem.
+
+Usage:
+    day18.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from typing import NamedTuple
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day18"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = ".^^.^.^^^^"
+# example room is 10 rows long
+EXAMPLE_ARGS = (10,)
+EXAMPLE_ANSWERS = (38, None)
+
+
+class TrapRow(NamedTuple):
+    """Row of tiles packed into an int, a set bit is a trap."""
+
+    bits: int
+    width: int
+
+    @classmethod
+    def from_str(cls, line: str) -> TrapRow:
+        """Leftmost tile becomes the highest bit."""
+        return cls(int(line.translate(str.maketrans(".^", "01")), 2), len(line))
+
+    def next_row(self) -> TrapRow:
+        """Return the row below.
+
+        A tile is a trap exactly when one of its upper left and upper right
+        neighbors is a trap, so every tile is computed at once with shifts.
+        """
+        mask = (1 << self.width) - 1
+        return TrapRow(((self.bits << 1) ^ (self.bits >> 1)) & mask, self.width)
+
+    @property
+    def safe_count(self) -> int:
+        """Return number of safe tiles."""
+        return self.width - self.bits.bit_count()
+
+
+class Day18(Day):
+    """Day 18 of Advent of Code 2016."""
+
+    LARGE_ROOM = 400_000
+
+    def __init__(self, rows: int = 40) -> None:
+        """Set how many rows the room in part 1 has."""
+        self.rows = rows
+
+    def parse(self, puzzle_input: str) -> TrapRow:
+        """Return the first row of tiles."""
+        return TrapRow.from_str(puzzle_input.strip())
+
+    @staticmethod
+    def count_safe(first_row: TrapRow, rows: int) -> int:
+        """Return number of safe tiles in a room with `rows` rows."""
+        safe, row = 0, first_row
+        for _ in range(rows):
+            safe += row.safe_count
+            row = row.next_row()
+        LOG.info("%d safe tiles in %d rows", safe, rows)
+        return safe
+
+    def part1(self, data: TrapRow) -> int:
+        """Return number of safe tiles in the room.
+
+        Args:
+            data (TrapRow): first row of the room
+
+        Returns:
+            int
+        """
+        return self.count_safe(data, self.rows)
+
+    def part2(self, data: TrapRow) -> int:
+        """Return number of safe tiles in a room of 400000 rows.
+
+        Args:
+            data (TrapRow): first row of the room
+
+        Returns:
+            int
+        """
+        return self.count_safe(data, self.LARGE_ROOM)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 18, 2016
+    day = Day18(*EXAMPLE_ARGS) if args["--example"] else Day18()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # example only covers the small room
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
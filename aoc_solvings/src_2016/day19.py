@@ -0,0 +1,149 @@
+"""Advent of Code 2016 Day19 problem.
+
+Usage:
+    day19.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+from collections import deque
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day19"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "5"
+EXAMPLE_ANSWERS = (3, 2)
+
+
+def steal_left(elves: int) -> int:
+    """Return elf left with every present when each steals from their left.
+
+    This is the Josephus problem with every second elf removed, whose winner is
+    found by moving the highest set bit of the count to the bottom.
+    """
+    highest_power = 1 << (elves.bit_length() - 1)
+    return 2 * (elves - highest_power) + 1
+
+
+def steal_across(elves: int) -> int:
+    """Return elf left with every present when each steals from across the circle.
+
+    With `power` the largest power of 3 not above the count, winners climb by
+    one until `power` and by two after that.
+    """
+    power = 1
+    while power * 3 <= elves:
+        power *= 3
+    if elves == power:
+        return elves
+    if elves <= 2 * power:
+        return elves - power
+    return 2 * elves - 3 * power
+
+
+def simulate_steal_across(elves: int) -> int:
+    """Return winner of stealing across by playing the game out.
+
+    The circle is split into two queues so the elf across from the current
+    one is always at the front of the second queue.
+    """
+    left = deque(range(1, elves // 2 + 1))
+    right = deque(range(elves // 2 + 1, elves + 1))
+    while left and right:
+        if len(left) > len(right):
+            left.pop()
+        else:
+            right.popleft()
+        # current elf moves to the back, keeping the two halves balanced
+        right.append(left.popleft())
+        left.append(right.popleft())
+    return (left or right)[0]
+
+
+class Day19(Day):
+    """Day 19 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> int:
+        """Return number of elves in the circle."""
+        return int(puzzle_input)
+
+    def part1(self, data: int) -> int:
+        """Return elf that gets every present stealing from the left.
+
+        Args:
+            data (int): number of elves
+
+        Returns:
+            int
+        """
+        return steal_left(data)
+
+    def part2(self, data: int) -> int:
+        """Return elf that gets every present stealing from across the circle.
+
+        Args:
+            data (int): number of elves
+
+        Returns:
+            int
+        """
+        winner = steal_across(data)
+        LOG.info("elf %d wins with %d elves playing", winner, data)
+        return winner
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 19, 2016
+    day = Day19()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        assert simulate_steal_across(int(data)) == answers[1]
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
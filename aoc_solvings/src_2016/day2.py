@@ -0,0 +1,153 @@
+"""Advent of Code 2016 Day2 problem.
+
+Usage:
+    day2.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.grid import parse_grid
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.grid import parse_grid
+    from common.template import Day
+
+LOG_NAME = "day2"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    ULL
+    RRDDD
+    LURDL
+    UUUUD"""
+)
+EXAMPLE_ANSWERS = ("1985", "5DB3")
+
+MOVES = {"U": (0, -1), "D": (0, 1), "L": (-1, 0), "R": (1, 0)}
+# layouts are drawn as they look, spaces are gaps with no button
+SQUARE_LAYOUT = "123\n456\n789"
+DIAMOND_LAYOUT = "  1\n 234\n56789\n ABC\n  D"
+
+
+class Keypad:
+    """Keypad whose buttons come from a drawing of its layout."""
+
+    def __init__(self, layout: str) -> None:
+        """Place a button at every non-space character of the layout."""
+        self.buttons = {
+            (col, row): key
+            for row, line in enumerate(parse_grid(layout, pad=" "))
+            for col, key in enumerate(line)
+            if key != " "
+        }
+        self.positions = {key: pos for pos, key in self.buttons.items()}
+
+    def code(self, instructions: list[list[TwoDimPoint]], start: str = "5") -> str:
+        """Return the buttons pressed at the end of each line of moves.
+
+        A move that would leave the keypad, or land on a gap, is ignored.
+
+        Args:
+            instructions (list[list[TwoDimPoint]]): moves for each button
+            start (str, optional): button the finger starts on. Defaults to "5".
+
+        Returns:
+            str
+        """
+        col, row = self.positions[start]
+        code = ""
+        for moves in instructions:
+            for dcol, drow in moves:
+                if (col + dcol, row + drow) in self.buttons:
+                    col, row = col + dcol, row + drow
+            code += self.buttons[col, row]
+        return code
+
+
+class Day2(Day):
+    """Day 2 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> list[list[TwoDimPoint]]:
+        """Return the moves for each button press, one line per button."""
+        return [[MOVES[ch] for ch in line] for line in puzzle_input.splitlines()]
+
+    def part1(self, data: list[list[TwoDimPoint]]) -> str:
+        """Return bathroom code on a 3x3 keypad numbered 1 to 9, starting at 5.
+
+        Args:
+            data (list[list[TwoDimPoint]]): moves for each button
+
+        Returns:
+            str
+        """
+        return Keypad(SQUARE_LAYOUT).code(data)
+
+    def part2(self, data: list[list[TwoDimPoint]]) -> str:
+        """Return bathroom code on the diamond keypad, starting at 5.
+
+        Args:
+            data (list[list[TwoDimPoint]]): moves for each button
+
+        Returns:
+            str
+        """
+        code = Keypad(DIAMOND_LAYOUT).code(data)
+        LOG.info("diamond keypad code is %s", code)
+        return code
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 2, 2016
+    day = Day2()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
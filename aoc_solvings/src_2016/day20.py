@@ -0,0 +1,125 @@
+"""Advent of Code 2016 Day20 problem.
+
+Usage:
+    day20.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.ranges import RangeSet
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.ranges import RangeSet
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day20"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    5-8
+    0-2
+    4-7"""
+)
+# example firewall covers addresses 0 through 9
+EXAMPLE_ARGS = (9,)
+EXAMPLE_ANSWERS = (3, 2)
+
+
+class Day20(Day):
+    """Day 20 of Advent of Code 2016."""
+
+    def __init__(self, max_ip: int = 4294967295) -> None:
+        """Set the highest address in the firewall's range."""
+        self.max_ip = max_ip
+
+    def parse(self, puzzle_input: str) -> RangeSet:
+        """Return addresses allowed through the firewall."""
+        blocked = RangeSet(
+            tuple(map(int, line.split("-"))) for line in puzzle_input.splitlines()
+        )
+        LOG.info("blocklist merges down to %d ranges", len(blocked))
+        return blocked.complement(0, self.max_ip)
+
+    def part1(self, data: RangeSet) -> int:
+        """Return lowest address that isn't blocked.
+
+        Args:
+            data (RangeSet): allowed addresses
+
+        Raises:
+            AnswerNotFoundError: if every address is blocked
+
+        Returns:
+            int
+        """
+        for start, _ in data:
+            return start
+        raise AnswerNotFoundError()
+
+    def part2(self, data: RangeSet) -> int:
+        """Return number of addresses that aren't blocked.
+
+        Args:
+            data (RangeSet): allowed addresses
+
+        Returns:
+            int
+        """
+        return data.size()
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 20, 2016
+    day = Day20(*EXAMPLE_ARGS) if args["--example"] else Day20()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,212 @@
+"""Advent of Code 2016 Day21 problem.
+
+Usage:
+    day21.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day21"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    swap position 4 with position 0
+    swap letter d with letter b
+    reverse positions 0 through 4
+    rotate left 1 step
+    move position 1 to position 4
+    move position 3 to position 0
+    rotate based on position of letter b
+    rotate based on position of letter d"""
+)
+# example scrambles abcde, and unscrambles decab for part 2
+EXAMPLE_ARGS = "abcde", "decab"
+EXAMPLE_ANSWERS = ("decab", "abcde")
+# letters found at this index or later rotate one extra step
+EXTRA_ROTATION_INDEX = 4
+
+
+class InvalidOperationError(Exception):
+    """Error for when a line isn't a scrambling operation."""
+
+    def __init__(self, line: str, *args: object) -> None:
+        """Error message shows the line that couldn't be parsed."""
+        super().__init__(f"Unknown scrambling operation {line!r}", *args)
+
+
+def rotate_right(password: list[str], steps: int) -> list[str]:
+    """Return password rotated right, negative steps rotate left."""
+    steps %= len(password)
+    return password[-steps:] + password[:-steps]
+
+
+def letter_rotation(password: list[str], letter: str) -> int:
+    """Return steps right that `rotate based on position of letter` rotates."""
+    index = password.index(letter)
+    return 1 + index + (index >= EXTRA_ROTATION_INDEX)
+
+
+def scramble(password: list[str], words: list[str]) -> list[str]:
+    """Apply one scrambling operation given as its split words.
+
+    Raises:
+        InvalidOperationError: if the words aren't a scrambling operation
+    """
+    password = password.copy()
+    match words:
+        case ["swap", "position", x, "with", "position", y]:
+            x, y = int(x), int(y)
+            password[x], password[y] = password[y], password[x]
+        case ["swap", "letter", x, "with", "letter", y]:
+            x, y = password.index(x), password.index(y)
+            password[x], password[y] = password[y], password[x]
+        case ["rotate", "left" | "right" as direction, steps, _]:
+            steps = int(steps) * (1 if direction == "right" else -1)
+            password = rotate_right(password, steps)
+        case ["rotate", "based", "on", "position", "of", "letter", letter]:
+            password = rotate_right(password, letter_rotation(password, letter))
+        case ["reverse", "positions", x, "through", y]:
+            x, y = int(x), int(y)
+            password[x : y + 1] = password[x : y + 1][::-1]
+        case ["move", "position", x, "to", "position", y]:
+            password.insert(int(y), password.pop(int(x)))
+        case _:
+            raise InvalidOperationError(" ".join(words))
+    return password
+
+
+def unscramble(password: list[str], words: list[str]) -> list[str]:
+    """Undo one scrambling operation given as its split words.
+
+    Swaps and reversals are their own inverse, so they are just applied again.
+
+    Raises:
+        AnswerNotFoundError: if no password scrambles into the one being undone
+        InvalidOperationError: if the words aren't a scrambling operation
+    """
+    match words:
+        case ["rotate", "left" | "right" as direction, steps, unit]:
+            opposite = "left" if direction == "right" else "right"
+            return scramble(password, ["rotate", opposite, steps, unit])
+        case ["rotate", "based", "on", "position", "of", "letter", letter]:
+            # search the left rotations for the one that scrambles to this
+            for steps in range(len(password)):
+                undone = rotate_right(password, -steps)
+                if rotate_right(undone, letter_rotation(undone, letter)) == password:
+                    return undone
+            raise AnswerNotFoundError()
+        case ["move", "position", x, "to", "position", y]:
+            return scramble(password, ["move", "position", y, "to", "position", x])
+    return scramble(password, words)
+
+
+def scramble_step(password: list[str], words: list[str], *, undo: bool) -> list[str]:
+    """Apply, or undo, one scrambling operation given as its split words."""
+    return unscramble(password, words) if undo else scramble(password, words)
+
+
+class Day21(Day):
+    """Day 21 of Advent of Code 2016."""
+
+    def __init__(
+        self, password: str = "abcdefgh", scrambled: str = "fbgdceah"
+    ) -> None:
+        """Set the password to scramble and the scrambled one to undo."""
+        self.password, self.scrambled = password, scrambled
+
+    def parse(self, puzzle_input: str) -> list[list[str]]:
+        """Return each scrambling operation split into words."""
+        return [line.split() for line in puzzle_input.splitlines()]
+
+    def part1(self, data: list[list[str]]) -> str:
+        """Return the password after scrambling.
+
+        Args:
+            data (list[list[str]]): scrambling operations
+
+        Returns:
+            str
+        """
+        password = list(self.password)
+        for operation in data:
+            password = scramble_step(password, operation, undo=False)
+            LOG.debug("%s gives %s", " ".join(operation), "".join(password))
+        return "".join(password)
+
+    def part2(self, data: list[list[str]]) -> str:
+        """Return the password that scrambles into the given scrambled one.
+
+        Operations are undone in reverse order.
+
+        Args:
+            data (list[list[str]]): scrambling operations
+
+        Returns:
+            str
+        """
+        password = list(self.scrambled)
+        for operation in reversed(data):
+            password = scramble_step(password, operation, undo=True)
+            LOG.debug("undoing %s gives %s", " ".join(operation), "".join(password))
+        return "".join(password)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 21, 2016
+    day = Day21(*EXAMPLE_ARGS) if args["--example"] else Day21()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,215 @@
+"""Advent of Code 2016 Day22 problem.
+
+Usage:
+    day22.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+from itertools import pairwise
+from itertools import permutations
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+from textwrap import dedent
+from typing import TYPE_CHECKING
+from typing import NamedTuple
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.grid import render
+    from common.search import bfs_distance
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.grid import TwoDimPoint
+    from common.grid import render
+    from common.search import bfs_distance
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day22"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    root@ebhq-gridcenter# df -h
+    Filesystem            Size  Used  Avail  Use%
+    /dev/grid/node-x0-y0   10T    8T     2T   80%
+    /dev/grid/node-x0-y1   11T    6T     5T   54%
+    /dev/grid/node-x0-y2   32T   28T     4T   87%
+    /dev/grid/node-x1-y0    9T    7T     2T   77%
+    /dev/grid/node-x1-y1    8T    0T     8T    0%
+    /dev/grid/node-x1-y2   11T    7T     4T   63%
+    /dev/grid/node-x2-y0   10T    6T     4T   60%
+    /dev/grid/node-x2-y1    9T    8T     1T   88%
+    /dev/grid/node-x2-y2    9T    6T     3T   66%"""
+)
+EXAMPLE_ANSWERS = (7, 7)
+
+NODE = re.compile(r"node-x(\d+)-y(\d+)\s+(\d+)T\s+(\d+)T\s+(\d+)T")
+
+
+class Node(NamedTuple):
+    """Storage node usage in terabytes."""
+
+    size: int
+    used: int
+    avail: int
+
+
+class StorageGrid:
+    """Every node keyed by its (x, y) position."""
+
+    def __init__(self, nodes: dict[TwoDimPoint, Node]) -> None:
+        """Store nodes and find the grid size and the empty node."""
+        self.nodes = nodes
+        self.width = max(x for x, _ in nodes) + 1
+        self.height = max(y for _, y in nodes) + 1
+        self.empty = next(pos for pos, node in nodes.items() if node.used == 0)
+
+    def is_wall(self, pos: TwoDimPoint) -> bool:
+        """Return whether a node holds too much to ever move into the empty node."""
+        return self.nodes[pos].used > self.nodes[self.empty].size
+
+    def draw(self, goal: TwoDimPoint) -> str:
+        """Return grid drawn like the puzzle, `G` goal data, `_` empty, `#` wall."""
+
+        def cell(x: int, y: int) -> str:
+            if (x, y) == goal:
+                return "G"
+            if (x, y) == self.empty:
+                return "_"
+            return "#" if self.is_wall((x, y)) else "."
+
+        return render(self.width, self.height, cell)
+
+    def open_neighbors(
+        self, blocked: TwoDimPoint, pos: TwoDimPoint
+    ) -> Iterator[TwoDimPoint]:
+        """Yield adjacent nodes the empty node can swap with, avoiding `blocked`."""
+        for dx, dy in pairwise((0, 1, 0, -1, 0)):
+            new_pos = pos[0] + dx, pos[1] + dy
+            if (
+                new_pos in self.nodes
+                and new_pos != blocked
+                and not self.is_wall(new_pos)
+            ):
+                yield new_pos
+
+
+class Day22(Day):
+    """Day 22 of Advent of Code 2016."""
+
+    # moves to shift goal data one node left once the empty node is in front of it
+    STEPS_PER_SHUFFLE = 5
+
+    def parse(self, puzzle_input: str) -> StorageGrid:
+        """Return every node's usage keyed by position."""
+        return StorageGrid(
+            {
+                (int(x), int(y)): Node(int(size), int(used), int(avail))
+                for x, y, size, used, avail in NODE.findall(puzzle_input)
+            }
+        )
+
+    def part1(self, data: StorageGrid) -> int:
+        """Return number of viable pairs of nodes.
+
+        A pair is viable when the first isn't empty and all its data fits in
+        the second.
+
+        Args:
+            data (StorageGrid): storage nodes
+
+        Returns:
+            int
+        """
+        return sum(
+            0 < node_a.used <= node_b.avail
+            for node_a, node_b in permutations(data.nodes.values(), 2)
+        )
+
+    def part2(self, data: StorageGrid) -> int:
+        """Return fewest moves to bring the top right node's data to the top left.
+
+        Treated as a sliding puzzle where only the empty node moves: walk it in
+        front of the goal data without disturbing it, then every step left
+        takes one move plus four to bring the empty node back in front.
+
+        Args:
+            data (StorageGrid): storage nodes
+
+        Raises:
+            AnswerNotFoundError: if walls cut the empty node off from the goal
+
+        Returns:
+            int
+        """
+        goal = data.width - 1, 0
+        LOG.info("starting grid\n%s", data.draw(goal))
+        if goal == (0, 0):
+            return 0
+        in_front = goal[0] - 1, 0
+        approach = bfs_distance(
+            data.empty,
+            lambda pos: data.open_neighbors(goal, pos),
+            lambda pos: pos == in_front,
+        )
+        if approach is None:
+            raise AnswerNotFoundError()
+        return approach + 1 + self.STEPS_PER_SHUFFLE * (goal[0] - 1)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 22, 2016
+    day = Day22()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,171 @@
+"""Advent of Code 2016 Day24 problem.
+
+Usage:
+    day24.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+from itertools import pairwise
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.combinatorics import held_karp
+    from common.grid import TwoDimPoint
+    from common.grid import parse_grid
+    from common.search import bfs
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.combinatorics import held_karp
+    from common.grid import TwoDimPoint
+    from common.grid import parse_grid
+    from common.search import bfs
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day24"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    ###########
+    #0.1.....2#
+    #.#######.#
+    #4.......3#
+    ###########"""
+)
+EXAMPLE_ANSWERS = (14, 20)
+
+
+class Day24(Day):
+    """Day 24 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> list[list[int]]:
+        """Return steps between every pair of numbered locations in the ducts.
+
+        Runs a BFS from each numbered location, the result is indexed by the
+        location numbers.
+
+        Raises:
+            RaggedGridError: if the map isn't rectangular
+        """
+        grid = parse_grid(puzzle_input)
+        points = {
+            int(ch): (row, col)
+            for row, line in enumerate(grid)
+            for col, ch in enumerate(line)
+            if ch.isdigit()
+        }
+
+        def open_neighbors(pos: TwoDimPoint) -> Iterator[TwoDimPoint]:
+            for drow, dcol in pairwise((0, 1, 0, -1, 0)):
+                row, col = pos[0] + drow, pos[1] + dcol
+                in_bounds = 0 <= row < len(grid) and 0 <= col < len(grid[0])
+                if in_bounds and grid[row][col] != "#":
+                    yield row, col
+
+        located = {pos: number for number, pos in points.items()}
+        distances = [[0] * len(points) for _ in points]
+        for number, pos in points.items():
+            for reached, dist in bfs(pos, open_neighbors):
+                if reached in located:
+                    distances[number][located[reached]] = dist
+        LOG.info("distances between locations %s", distances)
+        return distances
+
+    @staticmethod
+    def _check_reachable(distances: list[list[int]]) -> None:
+        if any(
+            dist == 0 and start != end
+            for start, row in enumerate(distances)
+            for end, dist in enumerate(row)
+        ):
+            raise AnswerNotFoundError()
+
+    def part1(self, data: list[list[int]]) -> int:
+        """Return fewest steps from location 0 that visit every location.
+
+        Args:
+            data (list[list[int]]): steps between each pair of locations
+
+        Raises:
+            AnswerNotFoundError: if walls separate some locations
+
+        Returns:
+            int
+        """
+        self._check_reachable(data)
+        return held_karp(data, 0)
+
+    def part2(self, data: list[list[int]]) -> int:
+        """Return fewest steps visiting every location then returning to 0.
+
+        Args:
+            data (list[list[int]]): steps between each pair of locations
+
+        Raises:
+            AnswerNotFoundError: if walls separate some locations
+
+        Returns:
+            int
+        """
+        self._check_reachable(data)
+        return held_karp(data, 0, closed=True)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 24, 2016
+    day = Day24()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,113 @@
+"""Advent of Code 2016 Day25 problem.
+
+Usage:
+    day25.py [--local] [--verbose]
+
+Options:
+    --local     Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose   Use python logging to get verbose output of what is going on
+                in a log file.
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+    from src_2016.assembunny import Assembunny
+    from src_2016.assembunny import Instruction
+    from src_2016.assembunny import parse_program
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+    from src_2016.assembunny import Assembunny
+    from src_2016.assembunny import Instruction
+    from src_2016.assembunny import parse_program
+
+LOG_NAME = "day25"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+
+class Day25(Day):
+    """Day 25 of Advent of Code 2016."""
+
+    # give up on proving a loop after this many correct outputs and accept it
+    MAX_SIGNAL_LEN = 10_000
+
+    def parse(self, puzzle_input: str) -> list[Instruction]:
+        """Return the assembunny program."""
+        return parse_program(puzzle_input)
+
+    @classmethod
+    def is_clock_signal(cls, program: list[Instruction], a: int) -> bool:
+        """Return whether program outputs 0, 1, 0, 1, ... forever.
+
+        Forever is proven by the machine reaching the same state at an output
+        while expecting the same next bit, after which it must repeat.
+        """
+        vm = Assembunny(program, a=a)
+        seen: set[tuple[int, ...]] = set()
+        for emitted, signal in enumerate(vm.execute()):
+            expected = emitted % 2
+            if signal != expected:
+                return False
+            if (state := (expected, *vm.state)) in seen:
+                return True
+            seen.add(state)
+            if emitted >= cls.MAX_SIGNAL_LEN:
+                return True
+        # program halted, which is not a clock
+        return False
+
+    def part1(self, data: list[Instruction]) -> int:
+        """Return lowest positive initial register a that makes a clock signal.
+
+        Args:
+            data (list[Instruction]): assembunny program
+
+        Returns:
+            int
+        """
+        a = 1
+        while not self.is_clock_signal(data, a):
+            LOG.debug("a=%d does not produce a clock signal", a)
+            a += 1
+        return a
+
+    def part2(self, data: list[Instruction]) -> None:
+        """Do nothing."""
+        LOG.info("There is no Part2 for this year.")
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 25, 2016
+    day = Day25()
+    if args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+
+    if args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data)
+    print(answers)
+    # no part 2 to submit for the last day
+    for ans, part in zip(answers, "a", strict=False):
+        submit(ans, day=DAY, year=YEAR, part=part)
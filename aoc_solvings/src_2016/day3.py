@@ -0,0 +1,146 @@
+"""Advent of Code 2016 Day3 problem.
+
+Usage:
+    day3.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day3"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    101 301 501
+    102 302 502
+    103 303 503
+    201 401 601
+    202 402 602
+    203 403 603"""
+)
+EXAMPLE_ANSWERS = (3, 6)
+
+Triangle = tuple[int, int, int]
+SIDES = 3
+
+
+class InvalidTriangleError(Exception):
+    """Error for when a row isn't three side lengths."""
+
+    def __init__(self, line: str, *args: object) -> None:
+        """Error message shows the row that couldn't be parsed."""
+        super().__init__(f"Expected three side lengths, got {line!r}", *args)
+
+
+def is_triangle(sides: Triangle) -> bool:
+    """Return whether the longest side is shorter than the other two combined."""
+    shortest, middle, longest = sorted(sides)
+    return shortest + middle > longest
+
+
+class Day3(Day):
+    """Day 3 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> list[Triangle]:
+        """Return the three numbers listed on each row.
+
+        Columns can be separated by any amount of spaces or tabs.
+
+        Raises:
+            InvalidTriangleError: if a row isn't exactly three whole numbers
+        """
+        triangles = []
+        for line in puzzle_input.splitlines():
+            sides = line.split()
+            if len(sides) != SIDES or not all(side.isdecimal() for side in sides):
+                raise InvalidTriangleError(line)
+            triangles.append((int(sides[0]), int(sides[1]), int(sides[2])))
+        return triangles
+
+    def part1(self, data: list[Triangle]) -> int:
+        """Return how many rows are possible triangles.
+
+        Args:
+            data (list[Triangle]): side lengths of each row
+
+        Returns:
+            int
+        """
+        return sum(map(is_triangle, data))
+
+    def part2(self, data: list[Triangle]) -> int:
+        """Return how many possible triangles there are reading down columns.
+
+        Every group of three rows holds three triangles, one per column.
+
+        Args:
+            data (list[Triangle]): side lengths of each row
+
+        Returns:
+            int
+        """
+        column_triangles = [
+            column
+            for start in range(0, len(data), 3)
+            for column in zip(*data[start : start + 3], strict=True)
+        ]
+        LOG.info("read %d triangles down columns", len(column_triangles))
+        return sum(map(is_triangle, column_triangles))
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 3, 2016
+    day = Day3()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
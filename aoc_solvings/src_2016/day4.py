@@ -0,0 +1,171 @@
+"""Advent of Code 2016 Day4 problem.
+
+Usage:
+    day4.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+from collections import Counter
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+from textwrap import dedent
+from typing import NamedTuple
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import AnswerNotFoundError
+    from common.template import Day
+
+LOG_NAME = "day4"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    aaaaa-bbb-z-y-x-123[abxyz]
+    a-b-c-d-e-f-g-h-987[abcde]
+    not-a-real-room-404[oarel]
+    totally-real-room-200[decoy]"""
+)
+EXAMPLE_ANSWERS = (1514, None)
+
+ROOM = re.compile(r"([a-z-]+)-(\d+)\[([a-z]{5})\]")
+
+
+class InvalidRoomError(Exception):
+    """Error for when a line isn't an encrypted room listing."""
+
+    def __init__(self, line: str, *args: object) -> None:
+        """Error message shows the line that couldn't be parsed."""
+        super().__init__(f"Expected name-sector[checksum], got {line!r}", *args)
+
+
+class Room(NamedTuple):
+    """Encrypted room listing from the kiosk."""
+
+    name: str
+    sector: int
+    checksum: str
+
+    def is_real(self) -> bool:
+        """Return whether checksum is the five most common letters of the name.
+
+        Ties in count are broken alphabetically.
+        """
+        counts = Counter(self.name.replace("-", ""))
+        common = sorted(counts, key=lambda letter: (-counts[letter], letter))
+        return "".join(common[:5]) == self.checksum
+
+    def decrypt(self) -> str:
+        """Return the name with each letter shifted forward by the sector id."""
+        shift = self.sector % 26
+        return "".join(
+            " " if ch == "-" else chr((ord(ch) - ord("a") + shift) % 26 + ord("a"))
+            for ch in self.name
+        )
+
+
+class Day4(Day):
+    """Day 4 of Advent of Code 2016."""
+
+    def __init__(self, target: str = "northpole object") -> None:
+        """Set words of the decrypted room name part 2 looks for."""
+        self.target = target
+
+    def parse(self, puzzle_input: str) -> list[Room]:
+        """Return each room listing.
+
+        Raises:
+            InvalidRoomError: if a line isn't `name-sector[checksum]`
+        """
+        rooms = []
+        for line in puzzle_input.splitlines():
+            match = ROOM.fullmatch(line.strip())
+            if match is None:
+                raise InvalidRoomError(line)
+            name, sector, checksum = match.groups()
+            rooms.append(Room(name, int(sector), checksum))
+        return rooms
+
+    def part1(self, data: list[Room]) -> int:
+        """Return sum of sector ids of the real rooms.
+
+        Args:
+            data (list[Room]): room listings
+
+        Returns:
+            int
+        """
+        return sum(room.sector for room in data if room.is_real())
+
+    def part2(self, data: list[Room]) -> int:
+        """Return sector id of the real room whose decrypted name has the target.
+
+        Args:
+            data (list[Room]): room listings
+
+        Raises:
+            AnswerNotFoundError: if no real room's name contains the target
+
+        Returns:
+            int
+        """
+        for room in data:
+            if room.is_real() and self.target in (name := room.decrypt()):
+                LOG.info("found %r in sector %d", name, room.sector)
+                return room.sector
+        raise AnswerNotFoundError()
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 4, 2016
+    day = Day4()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # example has no room holding North Pole objects
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,138 @@
+"""Advent of Code 2016 Day5 problem.
+
+Usage:
+    day5.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+from collections.abc import Iterator
+import logging
+import os
+from pathlib import Path
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.hashing import parallel_chunks
+    from common.hashing import prefixed_hashes
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.hashing import parallel_chunks
+    from common.hashing import prefixed_hashes
+    from common.template import Day
+
+LOG_NAME = "day5"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "abc"
+EXAMPLE_ANSWERS = ("18f47a30", "05ace8e3")
+
+
+class Day5(Day):
+    """Day 5 of Advent of Code 2016."""
+
+    PASSWORD_LEN = 8
+    INTERESTING_PREFIX = "00000"
+
+    def parse(self, puzzle_input: str) -> str:
+        """Return the door ID."""
+        return puzzle_input.strip()
+
+    def _interesting_hashes(self, door_id: str) -> Iterator[str]:
+        """Yield, in index order, every digest of the door ID starting with 00000."""
+        for chunk in parallel_chunks(prefixed_hashes, door_id, self.INTERESTING_PREFIX):
+            for index, digest in chunk:
+                LOG.debug("index %d gave interesting hash %s", index, digest)
+                yield digest
+
+    def part1(self, data: str) -> str:
+        """Return password made of the sixth character of the first 8 hashes.
+
+        Only hashes of `door_id + index` that start with five zeroes contribute.
+
+        Args:
+            data (str): door ID
+
+        Returns:
+            str
+        """
+        password = []
+        hashes = self._interesting_hashes(data)
+        for digest in hashes:
+            password.append(digest[5])
+            if len(password) == self.PASSWORD_LEN:
+                break
+        hashes.close()
+        return "".join(password)
+
+    def part2(self, data: str) -> str:
+        """Return password where each interesting hash also names the position.
+
+        Sixth character is the position and seventh is the character to put there.
+        Positions outside the password, or already filled, are ignored.
+
+        Args:
+            data (str): door ID
+
+        Returns:
+            str
+        """
+        password: list[str | None] = [None] * self.PASSWORD_LEN
+        hashes = self._interesting_hashes(data)
+        for digest in hashes:
+            position = int(digest[5], 16)
+            if position >= self.PASSWORD_LEN or password[position] is not None:
+                continue
+            password[position] = digest[6]
+            LOG.info("filled position %d, password now %s", position, password)
+            if all(ch is not None for ch in password):
+                break
+        hashes.close()
+        return "".join(ch for ch in password if ch is not None)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 5, 2016
+    day = Day5()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,125 @@
+"""Advent of Code 2016 Day6 problem.
+
+Usage:
+    day6.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+from collections import Counter
+import logging
+import os
+from pathlib import Path
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day6"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    eedadn
+    drvtee
+    eandsr
+    raavrd
+    atevrs
+    tsrnev
+    sdttsa
+    rasrtv
+    nssdts
+    ntnada
+    svetve
+    tesnvt
+    vntsnd
+    vrdear
+    dvrsen
+    enarar"""
+)
+EXAMPLE_ANSWERS = ("easter", "advent")
+
+
+class Day6(Day):
+    """Day 6 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> list[Counter[str]]:
+        """Return a letter Counter for each column of the repeated messages."""
+        columns = zip(*puzzle_input.split(), strict=True)
+        return [Counter(column) for column in columns]
+
+    def part1(self, data: list[Counter[str]]) -> str:
+        """Return message made of the most common letter in each column.
+
+        Args:
+            data (list[Counter[str]]): letter counts per column
+
+        Returns:
+            str
+        """
+        message = "".join(column.most_common(1)[0][0] for column in data)
+        LOG.info("decoded message with most common letters %r", message)
+        return message
+
+    def part2(self, data: list[Counter[str]]) -> str:
+        """Return message made of the least common letter in each column.
+
+        Args:
+            data (list[Counter[str]]): letter counts per column
+
+        Returns:
+            str
+        """
+        message = "".join(column.most_common()[-1][0] for column in data)
+        LOG.info("decoded message with least common letters %r", message)
+        return message
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 6, 2016
+    day = Day6()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,163 @@
+"""Advent of Code 2016 Day7 problem.
+
+Usage:
+    day7.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+from textwrap import dedent
+from typing import TYPE_CHECKING
+from typing import NamedTuple
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day7"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    abba[mnop]qrst
+    abcd[bddb]xyyx
+    aaaa[qwer]tyui
+    ioxxoj[asdfgh]zxcvbn
+    aba[bab]xyz
+    xyx[xyx]xyx
+    aaa[kek]eke
+    zazbz[bzb]cdb"""
+)
+EXAMPLE_ANSWERS = (2, 3)
+
+SEGMENT_SPLIT = re.compile(r"[\[\]]")
+
+
+class IPv7Address(NamedTuple):
+    """Address split into segments outside and inside square brackets."""
+
+    supernets: list[str]
+    hypernets: list[str]
+
+    @classmethod
+    def from_str(cls, line: str) -> IPv7Address:
+        """Split address on brackets, odd segments are the bracketed hypernets."""
+        segments = SEGMENT_SPLIT.split(line)
+        return cls(segments[::2], segments[1::2])
+
+    @staticmethod
+    def _has_abba(segment: str) -> bool:
+        return any(
+            a == d and b == c and a != b
+            for a, b, c, d in zip(
+                segment, segment[1:], segment[2:], segment[3:], strict=False
+            )
+        )
+
+    @staticmethod
+    def _abas(segment: str) -> Iterator[tuple[str, str]]:
+        """Yield the outer and inner letter of every ABA in segment."""
+        for a, b, c in zip(segment, segment[1:], segment[2:], strict=False):
+            if a == c and a != b:
+                yield a, b
+
+    def supports_tls(self) -> bool:
+        """Return whether any supernet has an ABBA while no hypernet does."""
+        return any(map(self._has_abba, self.supernets)) and not any(
+            map(self._has_abba, self.hypernets)
+        )
+
+    def supports_ssl(self) -> bool:
+        """Return whether an ABA in a supernet has its BAB in a hypernet."""
+        babs = {
+            f"{b}{a}{b}" for segment in self.supernets for a, b in self._abas(segment)
+        }
+        return any(bab in segment for segment in self.hypernets for bab in babs)
+
+
+class Day7(Day):
+    """Day 7 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> list[IPv7Address]:
+        """Return each line as an address split into its segments."""
+        return [IPv7Address.from_str(line) for line in puzzle_input.splitlines()]
+
+    def part1(self, data: list[IPv7Address]) -> int:
+        """Return number of addresses that support TLS.
+
+        Args:
+            data (list[IPv7Address]): addresses
+
+        Returns:
+            int
+        """
+        return sum(address.supports_tls() for address in data)
+
+    def part2(self, data: list[IPv7Address]) -> int:
+        """Return number of addresses that support SSL.
+
+        Args:
+            data (list[IPv7Address]): addresses
+
+        Returns:
+            int
+        """
+        return sum(address.supports_ssl() for address in data)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 7, 2016
+    day = Day7()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,203 @@
+"""Advent of Code 2016 Day8 problem.
+
+Usage:
+    day8.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+from __future__ import annotations
+
+# Standard Library
+from dataclasses import dataclass
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+from textwrap import dedent
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.ocr import read_letters
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.ocr import read_letters
+    from common.template import Day
+
+LOG_NAME = "day8"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = dedent(
+    """\
+    rect 3x2
+    rotate column x=1 by 1
+    rotate row y=0 by 4
+    rotate column x=1 by 1"""
+)
+# example screen is 7 wide and 3 tall
+EXAMPLE_ARGS = 7, 3
+EXAMPLE_ANSWERS = (6, None)
+
+INSTRUCTION = re.compile(
+    r"(?P<op>rect|rotate row|rotate column) \D*(?P<first>\d+)\D+(?P<second>\d+)"
+)
+
+
+class InvalidInstructionError(Exception):
+    """Error for when a line isn't a screen operation."""
+
+    def __init__(self, line: str, *args: object) -> None:
+        """Error message shows the line that couldn't be parsed."""
+        super().__init__(f"Unknown screen instruction {line!r}", *args)
+
+
+@dataclass(slots=True, frozen=True)
+class Instruction:
+    """One screen operation with its two numeric arguments."""
+
+    op: str
+    first: int
+    second: int
+
+    @classmethod
+    def from_str(cls, line: str) -> Instruction:
+        """Parse a `rect AxB` or `rotate row/column ... by B` line."""
+        if (match := INSTRUCTION.match(line)) is None:
+            raise InvalidInstructionError(line)
+        return cls(match["op"], int(match["first"]), int(match["second"]))
+
+
+class Screen:
+    """Grid of pixels that are either lit or dark."""
+
+    def __init__(self, width: int, height: int) -> None:
+        """Initialize with every pixel dark."""
+        self.width, self.height = width, height
+        self.pixels = [[False] * width for _ in range(height)]
+
+    def __str__(self) -> str:
+        """Render lit pixels as `#` and dark ones as `.`."""
+        return "\n".join(self.rows())
+
+    def rows(self) -> list[str]:
+        """Return each row of the screen rendered as text."""
+        return ["".join("#" if lit else "." for lit in row) for row in self.pixels]
+
+    def rect(self, width: int, height: int) -> None:
+        """Turn on every pixel in the top left `width` by `height` rectangle."""
+        for row in self.pixels[:height]:
+            row[:width] = [True] * width
+
+    def rotate_row(self, row: int, by: int) -> None:
+        """Shift a row right by `by` pixels, wrapping around."""
+        by %= self.width
+        self.pixels[row] = self.pixels[row][-by:] + self.pixels[row][:-by]
+
+    def rotate_column(self, col: int, by: int) -> None:
+        """Shift a column down by `by` pixels, wrapping around."""
+        column = [row[col] for row in self.pixels]
+        by %= self.height
+        for row, lit in zip(self.pixels, column[-by:] + column[:-by], strict=True):
+            row[col] = lit
+
+    def apply(self, instruction: Instruction) -> None:
+        """Perform the screen operation."""
+        match instruction.op:
+            case "rect":
+                self.rect(instruction.first, instruction.second)
+            case "rotate row":
+                self.rotate_row(instruction.first, instruction.second)
+            case "rotate column":
+                self.rotate_column(instruction.first, instruction.second)
+
+    @property
+    def lit_count(self) -> int:
+        """Return number of lit pixels."""
+        return sum(map(sum, self.pixels))
+
+
+class Day8(Day):
+    """Day 8 of Advent of Code 2016."""
+
+    def __init__(self, width: int = 50, height: int = 6) -> None:
+        """Set the size of the screen being driven."""
+        self.width, self.height = width, height
+
+    def parse(self, puzzle_input: str) -> Screen:
+        """Return the screen after running every instruction."""
+        screen = Screen(self.width, self.height)
+        for line in puzzle_input.splitlines():
+            screen.apply(instruction := Instruction.from_str(line))
+            LOG.debug("after %s screen is\n%s", instruction, screen)
+        return screen
+
+    def part1(self, data: Screen) -> int:
+        """Return how many pixels are lit after the instructions.
+
+        Args:
+            data (Screen): final screen
+
+        Returns:
+            int
+        """
+        return data.lit_count
+
+    def part2(self, data: Screen) -> str:
+        """Return the code displayed on the screen.
+
+        Args:
+            data (Screen): final screen
+
+        Returns:
+            str
+        """
+        LOG.info("final screen\n%s", data)
+        return read_letters(data.rows(), cell_width=5)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 8, 2016
+    day = Day8(*EXAMPLE_ARGS) if args["--example"] else Day8()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+        # example screen is too small to draw letters
+        args["--parts"] = ["a"]
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,141 @@
+"""Advent of Code 2016 Day9 problem.
+
+Usage:
+    day9.py [--example [--quiet] | --local] [--verbose] [--parts=<char> ...]
+
+Options:
+    --example       Use example input rather than running personal input.
+    --local         Use problem data stored in local data folder as `inputYEAR-DAY.txt`
+    --verbose       Use python logging to get verbose output of what is going on
+                    in a log file.
+    --quiet         Disable logging for example mode.
+    --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
+"""
+# Standard Library
+import logging
+import os
+from pathlib import Path
+import re
+import sys
+
+# External Party
+from aocd import get_data
+from aocd import submit
+from docopt import docopt
+
+try:
+    # My Modules
+    from common.template import Day
+except ImportError:
+    sys.path.insert(0, os.path.dirname(sys.path[0]))
+    # My Modules
+    from common.template import Day
+
+LOG_NAME = "day9"
+LOG = logging.getLogger(LOG_NAME)
+LOG.setLevel(logging.CRITICAL)
+
+EXAMPLE = "(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN"
+EXAMPLE_ANSWERS = (238, 445)
+
+MARKER = re.compile(r"\((\d+)x(\d+)\)")
+
+
+class Day9(Day):
+    """Day 9 of Advent of Code 2016."""
+
+    def parse(self, puzzle_input: str) -> str:
+        """Return compressed file with all whitespace removed."""
+        return "".join(puzzle_input.split())
+
+    @classmethod
+    def decompressed_length(
+        cls, data: str, start: int = 0, end: int | None = None, *, recursive: bool
+    ) -> int:
+        """Return length of `data[start:end]` once decompressed.
+
+        Only lengths are tracked, the decompressed text is never built, so the
+        version two format can expand to many gigabytes without issue.
+
+        Args:
+            data (str): compressed text
+            start (int, optional): index to start from. Defaults to 0.
+            end (int | None, optional): index to stop at. Defaults to end of data.
+            recursive (bool): whether markers inside repeated data are expanded
+
+        Returns:
+            int
+        """
+        end = len(data) if end is None else end
+        length, pos = 0, start
+        while pos < end:
+            if (marker := MARKER.match(data, pos, end)) is None:
+                length += 1
+                pos += 1
+                continue
+            span, repeat = int(marker[1]), int(marker[2])
+            pos = marker.end()
+            span_end = min(pos + span, end)
+            span_len = (
+                cls.decompressed_length(data, pos, span_end, recursive=True)
+                if recursive
+                else span_end - pos
+            )
+            LOG.debug("marker %s expands to %d characters", marker[0], span_len)
+            length += span_len * repeat
+            pos = span_end
+        return length
+
+    def part1(self, data: str) -> int:
+        """Return decompressed length, markers in repeated data are left as is.
+
+        Args:
+            data (str): compressed file
+
+        Returns:
+            int
+        """
+        return self.decompressed_length(data, recursive=False)
+
+    def part2(self, data: str) -> int:
+        """Return decompressed length where markers in repeated data also expand.
+
+        Args:
+            data (str): compressed file
+
+        Returns:
+            int
+        """
+        return self.decompressed_length(data, recursive=True)
+
+
+if __name__ == "__main__":
+    global args
+    args = docopt(__doc__)  # type: ignore
+    DAY, YEAR = 9, 2016
+    day = Day9()
+    if args["--example"] or args["--verbose"]:
+        handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
+        handle.setFormatter(
+            logging.Formatter("%(funcName)s-%(levelname)s:%(lineno)d %(message)s")
+        )
+        LOG.addHandler(handle)
+        LOG.setLevel(logging.DEBUG)
+    if args["--quiet"]:
+        logging.disable(logging.CRITICAL)
+
+    if args["--example"]:
+        data = EXAMPLE
+    elif args["--local"]:
+        data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
+    else:
+        data = get_data(day=DAY, year=YEAR)
+    answers = day.solve(data, parts=args["--parts"][0])
+    print(answers)
+    if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
+        sys.exit(0)
+    for ans, part in zip(answers, "ab", strict=True):
+        if part not in args["--parts"]:
+            continue
+        submit(ans, day=DAY, year=YEAR, part=part)
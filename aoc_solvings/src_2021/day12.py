@@ -47,6 +47,7 @@ EXAMPLE = dedent(
     A-end
     b-end"""
 )
+EXAMPLE_ANSWERS = (10, 36)
 
 
 class Day12(Day):
@@ -158,7 +159,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
-        assert answers == (10, 36)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
@@ -61,6 +61,7 @@ EXAMPLE = dedent(
     fold along y=7
     fold along x=5"""
 )
+EXAMPLE_ANSWERS = (17, None)
 
 
 @dataclass(unsafe_hash=True)
@@ -241,6 +242,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     # can't auto submit 'b' since its based on the text layout from folding
     for ans, part in zip(answers, ["a"], strict=True):
@@ -65,6 +65,7 @@ EXAMPLE = dedent(
     CC -> N
     CN -> C"""
 )
+EXAMPLE_ANSWERS = (1588, 2188189693529)
 
 
 @dataclass(unsafe_hash=True)
@@ -206,6 +207,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
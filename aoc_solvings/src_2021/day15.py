@@ -62,6 +62,7 @@ EXAMPLE = dedent(
     1293138521
     2311944581"""
 )
+EXAMPLE_ANSWERS = (40, 315)
 
 
 @dataclass(slots=True, unsafe_hash=True)
@@ -270,7 +271,7 @@ if __name__ == "__main__":
     answers = day.solve(data, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (40, 315)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
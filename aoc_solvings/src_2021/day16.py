@@ -47,6 +47,7 @@ EXAMPLE = dedent(
     """\
     A0016C880162017C3686B18A3D4780"""
 )
+EXAMPLE_ANSWERS = (31, None)
 
 VALBIT_ID = 4
 
@@ -288,6 +289,7 @@ if __name__ == "__main__":
     answers = day.solve(data, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
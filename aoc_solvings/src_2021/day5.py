@@ -59,6 +59,7 @@ EXAMPLE = dedent(
     0,0 -> 8,8
     5,5 -> 8,2"""
 )
+EXAMPLE_ANSWERS = (5, 12)
 
 
 class Point:
@@ -208,7 +209,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (5, 12)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -12,7 +12,6 @@ Options:
     --parts PART    Do only specified part, options are 'a', 'b', or 'ab'. [default: ab]
 """
 # Standard Library
-from collections.abc import Callable
 import logging
 import os
 from pathlib import Path
@@ -26,10 +25,12 @@ from docopt import docopt
 
 try:
     # My Modules
+    from common.ocr import read_letters
     from common.template import Day
 except ImportError:
     sys.path.insert(0, os.path.dirname(sys.path[0]))
     # My Modules
+    from common.ocr import read_letters
     from common.template import Day
 
 LOG_NAME = "day10"
@@ -185,6 +186,7 @@ EXAMPLE = dedent(
     noop
     noop"""
 )
+EXAMPLE_ANSWERS = (13140, None)
 
 
 class Day10(Day):
@@ -198,7 +200,7 @@ class Day10(Day):
         return [line.split(" ") for line in data_input.splitlines()]
 
     @staticmethod
-    def _print_crt(print_f: Callable, cycle_no: int, register_x: int) -> None:
+    def _draw_crt(screen: list[str], cycle_no: int, register_x: int) -> None:
         pixel = (cycle_no - 1) % 40
         LOG.debug(
             "putting a %r during cycle %d with register %d",
@@ -206,23 +208,17 @@ class Day10(Day):
             cycle_no,
             register_x,
         )
-        print_f("#" if register_x - 1 <= pixel <= register_x + 1 else " ", end="")
-        if cycle_no != 0 and cycle_no % 40 == 0:
-            print_f("")
+        screen.append("#" if register_x - 1 <= pixel <= register_x + 1 else " ")
 
     @classmethod
     def _process_signal_change(
-        cls, cycle_up: int, cycle_no: int, register_x: int, *, verbose: bool
+        cls, cycle_up: int, cycle_no: int, register_x: int, screen: list[str]
     ) -> int:
         """Return increment to signal_strengths."""
         signal_strengths_incr = 0
         for _ in range(cycle_up):
             cycle_no += 1
-            cls._print_crt(
-                print if verbose else lambda *args, **kwargs: None,
-                cycle_no,
-                register_x,
-            )
+            cls._draw_crt(screen, cycle_no, register_x)
             if (
                 cycle_no == cls.SIGNAL_STRENGTH_START
                 or (cycle_no - cls.SIGNAL_STRENGTH_START) % cls.SIGNAL_STRENGTH_REPEAT
@@ -243,9 +239,12 @@ class Day10(Day):
         return (1, 0) if len(command) == 1 else (2, int(command[1]))
 
     @classmethod
-    def _process_opcodes(cls, commands: list[list[str]], verbose: bool = False) -> int:
+    def _process_opcodes(
+        cls, commands: list[list[str]], screen: list[str] | None = None
+    ) -> int:
         cycle_no, register_x = 0, 1
         signal_strengths = 0
+        screen = [] if screen is None else screen
         for command in commands:
             cycles_to_complete, register_change = cls._process_command_format(command)
             LOG.debug(
@@ -253,13 +252,22 @@ class Day10(Day):
             )
 
             signal_strengths += cls._process_signal_change(
-                cycles_to_complete, cycle_no, register_x, verbose=verbose
+                cycles_to_complete, cycle_no, register_x, screen
             )
             cycle_no += cycles_to_complete
             register_x += register_change
 
         return signal_strengths
 
+    @classmethod
+    def crt_rows(cls, data: list[list[str]]) -> list[str]:
+        """Return each 40 pixel row the CRT draws, `#` lit and space dark."""
+        screen: list[str] = []
+        cls._process_opcodes(data, screen)
+        return [
+            "".join(screen[start : start + 40]) for start in range(0, len(screen), 40)
+        ]
+
     def part1(self, data: list[list[str]]) -> int:
         """Find sum of first 6 interesting signal strength.
 
@@ -276,17 +284,22 @@ class Day10(Day):
         """
         return self._process_opcodes(data)
 
-    def part2(self, data: list[list[str]]) -> None:
-        """Render the sprite position across commands.
+    def part2(self, data: list[list[str]]) -> str:
+        """Return the letters the sprite renders across commands.
 
         CRT (cathode-ray tube) is able to draw one pixel per cycle.
         The CRT screen is a 40 wide by 6 high - meaning a total of 240 cycles
-        to fill screen. Render this on screen to get answer
+        to fill screen. The lit pixels spell out capital letters.
 
         Args:
             data (list[list[str]]): commands of opcodes
+
+        Returns:
+            str: letters drawn on the CRT
         """
-        self._process_opcodes(data, verbose=True)
+        rows = self.crt_rows(data)
+        LOG.info("CRT shows\n%s", "\n".join(rows))
+        return read_letters(rows, cell_width=5)
 
 
 if __name__ == "__main__":
@@ -306,6 +319,8 @@ if __name__ == "__main__":
 
     if args["--example"]:
         data = EXAMPLE
+        # example screen is a test pattern rather than letters
+        args["--parts"] = ["a"]
     elif args["--local"]:
         data = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
     else:
@@ -313,7 +328,7 @@ if __name__ == "__main__":
     answers = day.solve(data, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (13140, None)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -73,6 +73,7 @@ EXAMPLE = dedent(
             If true: throw to monkey 0
             If false: throw to monkey 1"""
 )
+EXAMPLE_ANSWERS = (10605, 2713310158)
 
 
 @dataclass
@@ -245,6 +246,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
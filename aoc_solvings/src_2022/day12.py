@@ -50,6 +50,7 @@ EXAMPLE = dedent(
     acctuvwj
     abdefghi"""
 )
+EXAMPLE_ANSWERS = (31, 29)
 
 TwoDimPoint = tuple[int, int]
 
@@ -205,7 +206,7 @@ if __name__ == "__main__":
     answers = day.solve(data, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (31, 29)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -67,6 +67,7 @@ EXAMPLE = dedent(
     [1,[2,[3,[4,[5,6,7]]]],8,9]
     [1,[2,[3,[4,[5,6,0]]]],8,9]"""
 )
+EXAMPLE_ANSWERS = (13, 140)
 
 
 class Day13(Day):
@@ -237,7 +238,7 @@ if __name__ == "__main__":
     answers = day.solve(data, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (13, 140)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
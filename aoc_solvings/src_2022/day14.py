@@ -50,6 +50,7 @@ EXAMPLE = dedent(
     498,4 -> 498,6 -> 496,6
     503,4 -> 502,4 -> 502,9 -> 494,9"""
 )
+EXAMPLE_ANSWERS = (24, 93)
 
 
 class GridFillType(IntEnum):
@@ -216,6 +217,9 @@ class SandCave:
 class Day14(Day):
     """Day 14 of Advent of Code 2022."""
 
+    # parts pour sand into the cave grid
+    CHANGES_DATA = True
+
     @staticmethod
     def _gen_points_line(
         start: int, end: int, fixed_loc: int, *, fixed_row: bool = True
@@ -299,7 +303,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (24, 93)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -65,6 +65,9 @@ EXAMPLE = dedent(
     Sensor at x=14, y=3: closest beacon is at x=15, y=3
     Sensor at x=20, y=1: closest beacon is at x=15, y=3"""
 )
+# example checks row 10 and searches coordinates up to 20
+EXAMPLE_ARGS = 10, 20
+EXAMPLE_ANSWERS = (26, 56000011)
 
 
 @dataclass(frozen=True)
@@ -215,11 +218,13 @@ class Grid:
 class Day15(Day):
     """Day 15 of Advent of Code 2022."""
 
-    def __init__(self) -> None:
-        """Store default values."""
+    def __init__(
+        self, checking_row: int = 2_000_000, max_coord: int = 4_000_000
+    ) -> None:
+        """Set the row part 1 checks and the largest coordinate part 2 searches."""
         super().__init__()
-        self._checking_row = 2_000_000
-        self._max_col = self._max_depth = 4_000_000
+        self._checking_row = checking_row
+        self._max_col = self._max_depth = max_coord
 
     def parse(self, puzzle_input: str) -> list[tuple[Point, Point]]:
         """Return sensor, beacon pairs."""
@@ -264,7 +269,7 @@ if __name__ == "__main__":
     global args
     args = docopt(__doc__)  # type: ignore
     DAY, YEAR = 15, 2022
-    day = Day15()
+    day = Day15(*EXAMPLE_ARGS) if args["--example"] else Day15()
     if args["--example"] or args["--verbose"]:
         handle = logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.log", "w")
         handle.setFormatter(
@@ -276,8 +281,6 @@ if __name__ == "__main__":
         logging.disable(logging.CRITICAL)
 
     if args["--example"]:
-        day._checking_row = 10
-        day._max_col = day._max_depth = 20
         grid = EXAMPLE
     elif args["--local"]:
         grid = (Path(sys.path[0]) / "data" / f"input{YEAR}-{DAY}.txt").open().read()
@@ -286,7 +289,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (26, 56000011)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -49,6 +49,7 @@ EXAMPLE = dedent(
     Valve II has flow rate=0; tunnels lead to valves AA, JJ
     Valve JJ has flow rate=21; tunnel leads to valve II"""
 )
+EXAMPLE_ANSWERS = (1651, 1707)
 
 
 @dataclass
@@ -207,6 +208,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
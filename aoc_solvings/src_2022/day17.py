@@ -36,6 +36,7 @@ EXAMPLE = dedent(
     """\
     >>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"""
 )
+EXAMPLE_ANSWERS = (3068, 1514285714288)
 
 
 class NotTetris:
@@ -283,6 +284,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
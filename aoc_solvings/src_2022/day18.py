@@ -59,6 +59,7 @@ EXAMPLE = dedent(
     2,1,5
     2,3,5"""
 )
+EXAMPLE_ANSWERS = (64, 58)
 
 LavaPoint: TypeAlias = tuple[int, int, int]
 
@@ -158,7 +159,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (64, 58)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -53,6 +53,7 @@ EXAMPLE = dedent(
     Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
     Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian."""
 )
+EXAMPLE_ANSWERS = (33, 3472)
 
 
 class Robot(Enum):
@@ -335,7 +336,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (33, 3472)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -42,6 +42,7 @@ EXAMPLE = dedent(
     B X
     C Z"""
 )
+EXAMPLE_ANSWERS = (15, 12)
 
 
 class RPS(IntEnum):
@@ -152,7 +153,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (15, 12)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -52,6 +52,7 @@ EXAMPLE = dedent(
     0
     4"""
 )
+EXAMPLE_ANSWERS = (3, 1623178306)
 
 
 class Day20(Day):
@@ -210,7 +211,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (3, 1623178306)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
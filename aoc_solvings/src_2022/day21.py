@@ -63,6 +63,7 @@ EXAMPLE = dedent(
     drzm: hmdt - zczc
     hmdt: 32"""
 )
+EXAMPLE_ANSWERS = (152, 301)
 
 
 class MonkeyIsSmarterError(Exception):
@@ -186,6 +187,9 @@ class Monkey:
 class Day21(Day):
     """Day 21 of Advent of Code 2022."""
 
+    # part 2 turns the root monkey into an equality check
+    CHANGES_DATA = True
+
     MONKEY_OPS = {
         "+": add,
         "-": sub,
@@ -283,6 +287,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
@@ -70,6 +70,7 @@ EXAMPLE = dedent(
 
     10R5L5R10L4R5L5"""  # original 10R5L5R10L4R5L5
 )
+EXAMPLE_ANSWERS = (6032, 5031)
 
 
 class Facing(IntEnum):
@@ -578,7 +579,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (6032, 5031)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -55,6 +55,7 @@ EXAMPLE = dedent(
     ##.#.##
     .#..#.."""
 )
+EXAMPLE_ANSWERS = (110, 20)
 
 
 class GridLoc(NamedTuple):
@@ -307,6 +308,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, ["a", "b"], strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
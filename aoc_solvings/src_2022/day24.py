@@ -52,6 +52,7 @@ EXAMPLE = dedent(
     #<^v^^>#
     ######.#"""
 )
+EXAMPLE_ANSWERS = (18, 54)
 
 
 class GridLoc(NamedTuple):
@@ -300,7 +301,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (18, 54)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -56,6 +56,7 @@ EXAMPLE = dedent(
     1=
     122"""
 )
+EXAMPLE_ANSWERS = ("2=-1=0", None)
 
 
 class SNAFUCharacter(str, Enum):
@@ -215,6 +216,7 @@ if __name__ == "__main__":
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     # B part is readable only in terminal, can't upload via API
     for ans, part in zip(answers, "a", strict=False):
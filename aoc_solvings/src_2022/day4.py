@@ -1,5 +1,4 @@
 # Standard Library
-from operator import add
 import os
 import sys
 
@@ -9,10 +8,12 @@ from aocd import submit
 
 try:
     # My Modules
+    from common.ranges import RangeSet
     from common.template import Day
 except ImportError:
     sys.path.insert(0, os.path.dirname(sys.path[0]))
     # My Modules
+    from common.ranges import RangeSet
     from common.template import Day
 
 
@@ -24,14 +25,14 @@ class Day4(Day):
         return data_input.split("\n")
 
     @staticmethod
-    def _split_range(range_str: str) -> list[int]:
-        # always a length two list
-        return [int(x) for x in range_str.split("-")]
+    def _split_range(range_str: str) -> RangeSet:
+        start, end = (int(x) for x in range_str.split("-"))
+        return RangeSet([(start, end)])
 
     @staticmethod
-    def _split_range_pair(pair_str: str) -> list[list[int]]:
-        # always returns a list of one length 4 list
-        return [add(*[Day4._split_range(pair) for pair in pair_str.split(",")])]
+    def _split_range_pair(pair_str: str) -> tuple[RangeSet, RangeSet]:
+        first, second = pair_str.split(",")
+        return Day4._split_range(first), Day4._split_range(second)
 
     def part1(self, data: list[str]) -> int:
         """Return number of pairs where one range fully contains the other.
@@ -47,10 +48,8 @@ class Day4(Day):
             int: Number of pairs where one range fully contains the other
         """
         return sum(
-            (start_1 <= start_2 and end_1 >= end_2)
-            or (start_1 >= start_2 and end_1 <= end_2)
-            for pair in data
-            for start_1, end_1, start_2, end_2 in self._split_range_pair(pair)
+            first.issuperset(second) or second.issuperset(first)
+            for first, second in map(self._split_range_pair, data)
         )
 
     def part2(self, data: list[str]) -> int:
@@ -65,9 +64,7 @@ class Day4(Day):
             int: Number of pairs where there is any amount of overlap
         """
         return sum(
-            start_1 <= start_2 <= end_1 or start_2 <= start_1 <= end_2
-            for pair in data
-            for start_1, end_1, start_2, end_2 in self._split_range_pair(pair)
+            bool(first & second) for first, second in map(self._split_range_pair, data)
         )
 
 
@@ -62,6 +62,7 @@ EXAMPLE = dedent(
     5626152 d.ext
     7214296 k"""
 )
+EXAMPLE_ANSWERS = (95437, 24933642)
 
 
 class Day7(Day):
@@ -162,7 +163,7 @@ if __name__ == "__main__":
     answers = day.solve(grid, parts=args["--parts"][0])
     print(answers)
     if args["--example"]:
-        assert answers == (95437, 24933642)
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, "ab", strict=True):
         if part not in args["--parts"]:
@@ -36,7 +36,6 @@ except ImportError:
 
 LOG_NAME = "day9"
 LOG = logging.getLogger(LOG_NAME)
-LOG.addHandler(logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.txt", "w"))
 
 EXAMPLE = dedent(
     """\
@@ -49,6 +48,7 @@ EXAMPLE = dedent(
     L 5
     R 2"""
 )
+EXAMPLE_ANSWERS = (13, 1)
 EXAMPLE2 = dedent(
     """\
     R 5
@@ -215,11 +215,13 @@ if __name__ == "__main__":
     day = eval(f"Day{DAY}()")
     global args
     args = docopt(__doc__)  # type: ignore
+    LOG.addHandler(logging.FileHandler(f"{sys.path[1]}/{LOG_NAME}.txt", "w"))
     LOG.setLevel(logging.CRITICAL if not args["--example"] else logging.DEBUG)
     data = get_data(day=DAY, year=YEAR) if not args["--example"] else EXAMPLE
     answers = day.solve(data)
     print(answers)
     if args["--example"]:
+        assert answers == EXAMPLE_ANSWERS
         sys.exit(0)
     for ans, part in zip(answers, ["a", "b"], strict=True):
         submit(ans, day=DAY, year=YEAR, part=part)
@@ -0,0 +1,49 @@
+"""Shared pytest setup: import paths, the day loader and the `--runslow` option."""
+# Standard Library
+from collections.abc import Callable
+import importlib
+from pathlib import Path
+import sys
+
+# External Party
+import pytest
+
+ROOT = Path(__file__).resolve().parent.parent
+# day modules import `common` as a top level package, the package itself is
+# imported as `aoc_solvings` from the repository root
+sys.path[:0] = [str(ROOT), str(ROOT.parent)]
+
+# My Modules
+from common.template import Day  # noqa: E402
+
+
+@pytest.fixture()
+def load_day() -> Callable[..., Day]:
+    """Return a loader building `DayN(*args, **kwargs)` from `src_<year>.day<N>`."""
+
+    def _load(year: int, day: int, *args, **kwargs) -> Day:
+        module = importlib.import_module(f"src_{year}.day{day}")
+        return getattr(module, f"Day{day}")(*args, **kwargs)
+
+    return _load
+
+
+def pytest_addoption(parser: pytest.Parser) -> None:
+    """Add `--runslow` to opt in to the slow tests."""
+    parser.addoption(
+        "--runslow",
+        action="store_true",
+        help="run brute force examples",
+    )
+
+
+def pytest_collection_modifyitems(
+    config: pytest.Config, items: list[pytest.Item]
+) -> None:
+    """Skip tests marked slow unless `--runslow` was given."""
+    if config.getoption("--runslow"):
+        return
+    skip_slow = pytest.mark.skip(reason="needs --runslow to run")
+    for item in items:
+        if "slow" in item.keywords:
+            item.add_marker(skip_slow)
@@ -0,0 +1,71 @@
+"""Check every day against the answers accepted for personal puzzle input.
+
+Inputs come from the `data` folder, the same place `--local` reads
+`inputYEAR-DAY.txt` from. Answers live in `data/answers.json`, mapping year to
+day number to `[part1, part2]`. Days without a saved input are skipped.
+
+A day that runs past `TIME_LIMIT_SECONDS` is stopped and fails.
+"""
+# Standard Library
+from collections.abc import Callable
+from collections.abc import Iterator
+from contextlib import contextmanager
+import json
+from pathlib import Path
+import signal
+
+# External Party
+import pytest
+
+ROOT = Path(__file__).resolve().parent.parent
+# loose on purpose, this is to catch accidental blowups not small slowdowns
+TIME_LIMIT_SECONDS = 5
+
+
+def _golden_cases() -> list:
+    manifest = ROOT / "data" / "answers.json"
+    if not manifest.exists():
+        return []
+    return [
+        pytest.param(int(year), int(day), tuple(expected), id=f"{year}-day{day}")
+        for year, days in json.loads(manifest.read_text()).items()
+        for day, expected in days.items()
+    ]
+
+
+def _read_input(year: int, day: int) -> str:
+    puzzle_input = ROOT / "data" / f"input{year}-{day}.txt"
+    if not puzzle_input.exists():
+        pytest.skip(f"no saved input at {puzzle_input.relative_to(ROOT)}")
+    return puzzle_input.read_text()
+
+
+@contextmanager
+def _time_limit(seconds: float) -> Iterator[None]:
+    """Raise TimeoutError inside the block once `seconds` have passed."""
+
+    def _expire(signum: int, frame: object) -> None:
+        raise TimeoutError(f"took longer than {seconds}s")
+
+    previous = signal.signal(signal.SIGALRM, _expire)
+    signal.setitimer(signal.ITIMER_REAL, seconds)
+    try:
+        yield
+    finally:
+        signal.setitimer(signal.ITIMER_REAL, 0)
+        signal.signal(signal.SIGALRM, previous)
+
+
+def _parts(expected: tuple) -> str:
+    return "".join(
+        part for part, ans in zip("ab", expected, strict=True) if ans is not None
+    )
+
+
+@pytest.mark.parametrize(("year", "day", "expected"), _golden_cases())
+def test_answer(load_day: Callable, year: int, day: int, expected: tuple) -> None:
+    puzzle_input = _read_input(year, day)
+    solver = load_day(year, day)
+    with _time_limit(TIME_LIMIT_SECONDS):
+        answers = solver.solve(puzzle_input, parts=_parts(expected))
+    assert answers == expected
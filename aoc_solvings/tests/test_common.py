@@ -0,0 +1,176 @@
+"""Randomized checks of the shared `common` helpers against brute force.
+
+Each test draws small random cases from a seeded generator, so failures reproduce
+by seed without any extra dependency.
+"""
+# Standard Library
+from itertools import pairwise
+from itertools import permutations
+import math
+import random
+
+# External Party
+import pytest
+
+# My Modules
+from common.combinatorics import held_karp
+from common.grid import segment_intersection
+from common.number_theory import NoSolutionError
+from common.number_theory import crt
+from common.ocr import LETTERS
+from common.ocr import read_letters
+from common.ranges import RangeSet
+from common.search import bfs
+from common.search import bfs_distance
+from common.search import dfs
+
+SEEDS = range(25)
+
+
+def _random_range_set(rng: random.Random) -> tuple[RangeSet, set[int]]:
+    spans = []
+    for _ in range(rng.randint(0, 5)):
+        start = rng.randint(0, 40)
+        spans.append((start, start + rng.randint(-2, 8)))
+    covered = {value for start, end in spans for value in range(start, end + 1)}
+    return RangeSet(spans), covered
+
+
+def _covered(ranges: RangeSet) -> set[int]:
+    return {value for start, end in ranges for value in range(start, end + 1)}
+
+
+def _random_graph(rng: random.Random) -> dict[int, list[int]]:
+    size = rng.randint(1, 12)
+    return {
+        node: rng.sample(range(size), rng.randint(0, min(3, size)))
+        for node in range(size)
+    }
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_crt_matches_brute_force(seed: int) -> None:
+    rng = random.Random(seed)
+    congruences = [
+        (rng.randint(0, 20), rng.randint(1, 12)) for _ in range(rng.randint(1, 3))
+    ]
+    lcm = math.lcm(*(modulus for _, modulus in congruences))
+    matching = [
+        value
+        for value in range(lcm)
+        if all(value % modulus == residue % modulus for residue, modulus in congruences)
+    ]
+    if not matching:
+        with pytest.raises(NoSolutionError):
+            crt(congruences)
+    else:
+        assert crt(congruences) == (matching[0], lcm)
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_range_set_matches_python_sets(seed: int) -> None:
+    rng = random.Random(seed)
+    first, first_values = _random_range_set(rng)
+    second, second_values = _random_range_set(rng)
+
+    assert _covered(first) == first_values
+    assert _covered(first | second) == first_values | second_values
+    assert _covered(first & second) == first_values & second_values
+    assert first.size() == len(first_values)
+    assert first.issuperset(second) == (first_values >= second_values)
+    assert _covered(first.complement(0, 50)) == set(range(51)) - first_values
+    assert all((value in first) == (value in first_values) for value in range(-5, 55))
+    # merged spans never overlap or touch
+    spans = list(first)
+    assert all(end + 1 < start for (_, end), (start, _) in pairwise(spans))
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_range_set_laws(seed: int) -> None:
+    rng = random.Random(seed)
+    first, _ = _random_range_set(rng)
+    second, _ = _random_range_set(rng)
+    third, _ = _random_range_set(rng)
+
+    assert first | second == second | first
+    assert first & second == second & first
+    assert (first | second) | third == first | (second | third)
+    assert first & (second | third) == (first & second) | (first & third)
+    assert first | (first & second) == first
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_bfs_distances_match_relaxation(seed: int) -> None:
+    rng = random.Random(seed)
+    graph = _random_graph(rng)
+    # Bellman-Ford with unit weights as the reference shortest paths
+    expected = {0: 0}
+    for _ in graph:
+        for node, dist in list(expected.items()):
+            for neighbor in graph[node]:
+                if expected.get(neighbor, math.inf) > dist + 1:
+                    expected[neighbor] = dist + 1
+
+    assert dict(bfs(0, graph.__getitem__)) == expected
+    assert set(dfs(0, graph.__getitem__)) == set(expected)
+    for goal in graph:
+        assert bfs_distance(0, graph.__getitem__, goal.__eq__) == expected.get(goal)
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_held_karp_matches_permutations(seed: int) -> None:
+    rng = random.Random(seed)
+    size = rng.randint(1, 6)
+    distances = [[rng.randint(1, 20) for _ in range(size)] for _ in range(size)]
+    start = rng.randrange(size)
+    others = [node for node in range(size) if node != start]
+
+    def route_length(route: tuple[int, ...], *, closed: bool) -> int:
+        stops = [start, *route, start] if closed else [start, *route]
+        return sum(distances[a][b] for a, b in pairwise(stops))
+
+    for closed in (False, True):
+        expected = min(
+            route_length(route, closed=closed) for route in permutations(others)
+        )
+        assert held_karp(distances, start, closed=closed) == expected
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_segment_intersection_matches_walked_points(seed: int) -> None:
+    rng = random.Random(seed)
+
+    def random_segment() -> list[tuple[int, int]]:
+        x, y, length = rng.randint(-4, 4), rng.randint(-4, 4), rng.randint(0, 6)
+        dx, dy = rng.choice([(0, 1), (1, 0), (0, -1), (-1, 0)])
+        return [(x + dx * step, y + dy * step) for step in range(length + 1)]
+
+    walked, other = random_segment(), random_segment()
+    shared = [point for point in walked if point in other]
+    expected = shared[0] if shared else None
+    assert segment_intersection(walked[0], walked[-1], other[0], other[-1]) == expected
+
+
+def _draw_letters(text: str) -> list[str]:
+    """Draw each letter in its own 5 column cell with no forced gap between."""
+    glyphs = {letter: glyph for glyph, letter in LETTERS.items()}
+    return [
+        "".join(glyphs[letter][row].ljust(5, ".") for letter in text).replace(".", " ")
+        for row in range(6)
+    ]
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_read_letters_round_trip(seed: int) -> None:
+    rng = random.Random(seed)
+    letters = sorted(LETTERS.values())
+    text = "".join(rng.choices(letters, k=rng.randint(0, 8)))
+    # Y fills its whole cell, so make sure one always touches the next letter
+    at = rng.randint(0, len(text))
+    text = text[:at] + "Y" + rng.choice(letters) + text[at:]
+    assert read_letters(_draw_letters(text), cell_width=5) == text
+
+
+@pytest.mark.parametrize("text", ["EOARGPHYAO", "CFLELOYFCS", "ZJHRKCPLYJ"])
+def test_read_letters_after_wide_letter(text: str) -> None:
+    assert read_letters(_draw_letters(text), cell_width=5) == text
@@ -0,0 +1,79 @@
+"""Check every day against the worked example from its puzzle description.
+
+Examples are the `EXAMPLE` constant of each day module, so nothing is read from
+disk. Expected answers are the module's `EXAMPLE_ANSWERS`, the same ones its
+`--example` run asserts, with None for a part the example doesn't cover. Days
+whose example needs other settings than the puzzle pass `EXAMPLE_ARGS` to the
+constructor. Any day module with a non-empty `EXAMPLE` needs `EXAMPLE_ANSWERS`,
+so a new example can't go unchecked.
+"""
+# Standard Library
+from collections.abc import Callable
+import importlib
+from pathlib import Path
+from types import ModuleType
+
+# External Party
+import pytest
+
+ROOT = Path(__file__).resolve().parent.parent
+
+# days known not to reproduce their example yet, with the reason
+XFAIL = {
+    (2021, 15): "GridLoc neighbors unpack a dataclass",
+    (2021, 16): "nested operator packets fail to parse",
+    (2022, 2): "scores come out as (18, 18)",
+    (2022, 7): "part2 calls missing _compute_filesystem",
+    (2022, 9): "part methods read the docopt args global",
+}
+# days whose example only passes under some hash seeds, with the reason
+FLAKY = {
+    (2022, 19): "part2 pruning depends on set order, so on the hash seed",
+}
+# brute force examples, left for `pytest --runslow`
+SLOW = {(2016, 5), (2016, 14)}
+
+
+def _day_modules() -> dict[tuple[int, int], ModuleType]:
+    modules = {}
+    for path in ROOT.glob("src_*/day*.py"):
+        year = int(path.parent.name.removeprefix("src_"))
+        day = int(path.stem.removeprefix("day"))
+        modules[year, day] = importlib.import_module(f"src_{year}.day{day}")
+    return dict(sorted(modules.items()))
+
+
+def _example_cases() -> list:
+    cases = []
+    for (year, day), module in _day_modules().items():
+        if not hasattr(module, "EXAMPLE_ANSWERS"):
+            continue
+        marks = []
+        if (year, day) in XFAIL:
+            marks.append(pytest.mark.xfail(reason=XFAIL[year, day], strict=True))
+        if (year, day) in FLAKY:
+            marks.append(pytest.mark.xfail(reason=FLAKY[year, day], strict=False))
+        if (year, day) in SLOW:
+            marks.append(pytest.mark.slow)
+        cases.append(pytest.param(year, day, id=f"{year}-day{day}", marks=marks))
+    return cases
+
+
+@pytest.mark.parametrize(("year", "day"), _example_cases())
+def test_example(load_day: Callable, year: int, day: int) -> None:
+    module = importlib.import_module(f"src_{year}.day{day}")
+    solver = load_day(year, day, *getattr(module, "EXAMPLE_ARGS", ()))
+    expected = module.EXAMPLE_ANSWERS
+    parts = "".join(
+        part for part, ans in zip("ab", expected, strict=True) if ans is not None
+    )
+    assert solver.solve(module.EXAMPLE, parts=parts) == expected
+
+
+def test_every_example_has_answers() -> None:
+    missing = [
+        key
+        for key, module in _day_modules().items()
+        if getattr(module, "EXAMPLE", "") and not hasattr(module, "EXAMPLE_ANSWERS")
+    ]
+    assert not missing, f"add EXAMPLE_ANSWERS to the day modules of {missing}"
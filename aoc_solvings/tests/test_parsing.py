@@ -0,0 +1,108 @@
+"""Check parsers cope with the layouts puzzle input can arrive in."""
+# External Party
+import pytest
+
+# My Modules
+from common.grid import RaggedGridError
+from common.grid import parse_grid
+from common.template import normalize_input
+from src_2016.day2 import DIAMOND_LAYOUT
+from src_2016.day2 import MOVES
+from src_2016.day2 import SQUARE_LAYOUT
+from src_2016.day2 import Keypad
+from src_2016.day3 import Day3
+from src_2016.day3 import InvalidTriangleError
+from src_2016.day4 import Day4
+from src_2016.day4 import InvalidRoomError
+from src_2022.day4 import Day4 as Day4Of2022
+
+
+@pytest.mark.parametrize(
+    "puzzle_input",
+    [
+        "  5   10   25\n  3    4    5",
+        "5 10 25\n3 4 5",
+        "5\t10\t25\n3\t4\t5",
+        " 5 \t 10  25 \n3 4     5\t",
+    ],
+    ids=["padded", "single-space", "tabs", "mixed"],
+)
+def test_2016_day3_separators(puzzle_input: str) -> None:
+    assert Day3().parse(puzzle_input) == [(5, 10, 25), (3, 4, 5)]
+
+
+@pytest.mark.parametrize(
+    "puzzle_input",
+    ["5 10", "5 10 25 30", "5 ten 25", "5 -10 25", "5 10\u00b2 25"],
+    ids=["too-few", "too-many", "word", "negative", "superscript"],
+)
+def test_2016_day3_rejects_bad_rows(puzzle_input: str) -> None:
+    with pytest.raises(InvalidTriangleError):
+        Day3().parse(puzzle_input)
+
+
+@pytest.mark.parametrize(
+    "puzzle_input",
+    [
+        "2-4,6-8\n2-8,3-7",
+        "2-4,6-8\r\n2-8,3-7\r\n",
+        "\ufeff2-4,6-8\n2-8,3-7\n\n  \n",
+    ],
+    ids=["clean", "crlf", "bom-trailing-blank-lines"],
+)
+def test_solve_normalizes_input(puzzle_input: str) -> None:
+    assert normalize_input(puzzle_input) == "2-4,6-8\n2-8,3-7"
+    assert Day4Of2022().solve(puzzle_input) == (1, 1)
+
+
+def test_normalize_keeps_leading_whitespace() -> None:
+    assert normalize_input("    [D]\r\n[N] [C]   \n\n") == "    [D]\n[N] [C]   "
+
+
+def test_parse_grid_rejects_ragged_rows() -> None:
+    assert parse_grid("#.#\n...") == ["#.#", "..."]
+    with pytest.raises(RaggedGridError, match="Line 2"):
+        parse_grid("#.#\n..\n###")
+
+
+def test_parse_grid_pads_short_rows() -> None:
+    assert parse_grid("#.\n...\n#", pad=" ") == ["#. ", "...", "#  "]
+
+
+@pytest.mark.parametrize(
+    ("layout", "moves", "expected"),
+    [
+        (SQUARE_LAYOUT, ["ULL", "RRDDD", "LURDL", "UUUUD"], "1985"),
+        (SQUARE_LAYOUT, ["UUUU", "LLLL", "DDDD", "RRRR"], "2179"),
+        (DIAMOND_LAYOUT, ["ULL", "RRDDD", "LURDL", "UUUUD"], "5DB3"),
+        (DIAMOND_LAYOUT, ["U", "RRRR", "LLUUU", "DDDD"], "591D"),
+        ("45\n 6", ["L", "D", "RD"], "446"),
+    ],
+    ids=["square", "square-edges", "diamond", "diamond-gaps", "custom"],
+)
+def test_2016_day2_keypad_layouts(layout: str, moves: list[str], expected: str) -> None:
+    instructions = [[MOVES[move] for move in line] for line in moves]
+    assert Keypad(layout).code(instructions) == expected
+
+
+def test_2016_day2_keypad_buttons() -> None:
+    assert Keypad(SQUARE_LAYOUT).positions["5"] == (1, 1)
+    diamond = Keypad(DIAMOND_LAYOUT)
+    assert len(diamond.buttons) == 13
+    assert diamond.positions["5"] == (0, 2)
+    assert diamond.positions["D"] == (2, 4)
+
+
+def test_2016_day4_decrypts_room_names() -> None:
+    room = Day4().parse("qzmt-zixmtkozy-ivhz-343[zimth]")[0]
+    assert room.decrypt() == "very encrypted name"
+
+
+@pytest.mark.parametrize(
+    "line",
+    ["aaaaa-bbb-z-y-x[abxyz]", "aaaaa-bbb-z-y-x-123", "aaaaa-bbb-z-y-x-123[abx]"],
+    ids=["no-sector", "no-checksum", "short-checksum"],
+)
+def test_2016_day4_rejects_bad_rooms(line: str) -> None:
+    with pytest.raises(InvalidRoomError):
+        Day4().parse(line)
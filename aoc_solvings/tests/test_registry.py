@@ -0,0 +1,57 @@
+"""Check registered solutions take the place of the built in days."""
+# Standard Library
+from collections.abc import Iterator
+from importlib.metadata import EntryPoint
+
+# External Party
+import pytest
+
+# My Modules
+import aoc_solvings
+from aoc_solvings import InvalidEntryPointError
+from src_2016.day6 import EXAMPLE as EXAMPLE_2016_6
+from src_2016.day6 import Day6
+
+
+class Shouting(Day6):
+    """Day 6 of 2016 with its answers in capitals."""
+
+    def part1(self, data: list[str]) -> str:
+        """Return the part 1 message in capitals."""
+        return super().part1(data).upper()
+
+
+@pytest.fixture(autouse=True)
+def _keep_registry() -> Iterator[None]:
+    """Put the registry back the way it was after each test."""
+    saved = dict(aoc_solvings._REGISTERED)
+    yield
+    aoc_solvings._REGISTERED.clear()
+    aoc_solvings._REGISTERED.update(saved)
+
+
+def _entry_point(name: str) -> EntryPoint:
+    return EntryPoint(name, f"{__name__}:Shouting", aoc_solvings.ENTRY_POINT_GROUP)
+
+
+def test_register_replaces_built_in_day() -> None:
+    assert not aoc_solvings.is_registered(2016, 6)
+    aoc_solvings.register(2016, 6, Shouting)
+    assert aoc_solvings.is_registered(2016, 6)
+    assert aoc_solvings.solve(2016, 6, EXAMPLE_2016_6) == ("EASTER", "advent")
+
+
+def test_discover_registers_entry_points(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setattr(
+        aoc_solvings, "entry_points", lambda group: [_entry_point("2016-6")]
+    )
+    assert aoc_solvings.discover() == [(2016, 6)]
+    assert isinstance(aoc_solvings.load_day(2016, 6), Shouting)
+
+
+def test_discover_rejects_bad_names(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setattr(
+        aoc_solvings, "entry_points", lambda group: [_entry_point("day6")]
+    )
+    with pytest.raises(InvalidEntryPointError):
+        aoc_solvings.discover()
@@ -0,0 +1,53 @@
+"""Check the text drawings of screens and grids against the puzzle descriptions.
+
+These drawings only reach the logs or get read back as letters, so an answer can
+be right while the picture is wrong. Each expected picture is copied from its
+puzzle text.
+"""
+# Standard Library
+from textwrap import dedent
+
+# My Modules
+from src_2016.day8 import EXAMPLE as EXAMPLE_2016_8
+from src_2016.day8 import EXAMPLE_ARGS as EXAMPLE_ARGS_2016_8
+from src_2016.day8 import Day8
+from src_2016.day22 import EXAMPLE as EXAMPLE_2016_22
+from src_2016.day22 import Day22
+from src_2022.day10 import EXAMPLE as EXAMPLE_2022_10
+from src_2022.day10 import Day10
+
+
+def test_2016_day8_example_screen() -> None:
+    day = Day8(*EXAMPLE_ARGS_2016_8)
+    expected = dedent(
+        """\
+        .#..#.#
+        #.#....
+        .#....."""
+    )
+    assert str(day.parse(EXAMPLE_2016_8)) == expected
+
+
+def test_2016_day22_example_drawing() -> None:
+    day = Day22()
+    grid = day.parse(EXAMPLE_2016_22)
+    expected = dedent(
+        """\
+        ..G
+        ._.
+        #.."""
+    )
+    assert grid.draw((grid.width - 1, 0)) == expected
+
+
+def test_2022_day10_example_crt() -> None:
+    day = Day10()
+    rows = day.crt_rows(day.parse(EXAMPLE_2022_10))
+    assert [row.replace(" ", ".") for row in rows] == [
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ]
@@ -0,0 +1,38 @@
+"""Check the `selftest` command reports each part of each day on its own."""
+# My Modules
+from aoc_solvings.selftest import check
+from aoc_solvings.selftest import example_cases
+from common.template import Day
+
+
+class HalfDone(Day):
+    """Day whose first part works and whose second part isn't written yet."""
+
+    def parse(self, puzzle_input: str) -> str:
+        """Keep the input as is."""
+        return puzzle_input
+
+    def part1(self, data: str) -> int:
+        """Return length of the input."""
+        return len(data)
+
+    def part2(self, data: str) -> int:
+        """Not written yet."""
+        raise NotImplementedError
+
+
+def test_check_reports_each_part() -> None:
+    problems = check(HalfDone(), "abc", (3, 4))
+    assert problems["a"] is None
+    assert problems["b"].startswith("raised NotImplementedError")
+
+
+def test_check_wrong_answer() -> None:
+    assert check(HalfDone(), "abc", (4, None)) == {"a": "expected 4, got 3"}
+
+
+def test_example_cases_for_one_day() -> None:
+    cases = list(example_cases(2016, 3))
+    assert [case[:2] for case in cases] == [(2016, 3)]
+    _, _, solver, puzzle_input, expected = cases[0]
+    assert check(solver, puzzle_input, expected) == {"a": None, "b": None}
@@ -0,0 +1,77 @@
+"""Check the HTTP server answers like the library and turns away bad requests."""
+# Standard Library
+from collections.abc import Iterator
+from http import HTTPStatus
+from http.client import HTTPConnection
+from http.server import ThreadingHTTPServer
+import json
+import threading
+
+# External Party
+import pytest
+
+# My Modules
+from aoc_solvings import load_day
+from aoc_solvings.server import SolveHandler
+from aoc_solvings.server import solve_warm
+from src_2016.day6 import EXAMPLE as EXAMPLE_2016_6
+from src_2022.day14 import EXAMPLE as EXAMPLE_2022_14
+from src_2022.day21 import EXAMPLE as EXAMPLE_2022_21
+
+
+@pytest.fixture()
+def connection() -> Iterator[HTTPConnection]:
+    """Yield a connection to a server running on a free port."""
+    server = ThreadingHTTPServer(("127.0.0.1", 0), SolveHandler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    connection = HTTPConnection(*server.server_address, timeout=10)
+    yield connection
+    connection.close()
+    server.shutdown()
+    server.server_close()
+
+
+def _post(
+    connection: HTTPConnection, path: str, body: bytes, headers: dict[str, str]
+) -> tuple[int, dict]:
+    connection.request("POST", path, body=body, headers=headers)
+    response = connection.getresponse()
+    return response.status, json.loads(response.read())
+
+
+def test_solve(connection: HTTPConnection) -> None:
+    body = EXAMPLE_2016_6.encode()
+    status, answer = _post(
+        connection, "/solve/2016/6", body, {"Content-Length": str(len(body))}
+    )
+    assert status == HTTPStatus.OK
+    assert (answer["part1"], answer["part2"]) == ("easter", "advent")
+
+
+@pytest.mark.parametrize("parts", ["c", "ba", "abc"])
+def test_unknown_parts(connection: HTTPConnection, parts: str) -> None:
+    body = EXAMPLE_2016_6.encode()
+    status, _ = _post(
+        connection,
+        f"/solve/2016/6?parts={parts}",
+        body,
+        {"Content-Length": str(len(body))},
+    )
+    assert status == HTTPStatus.BAD_REQUEST
+
+
+@pytest.mark.parametrize("length", ["", "many", "-1"])
+def test_bad_content_length(connection: HTTPConnection, length: str) -> None:
+    status, _ = _post(connection, "/solve/2016/6", b"", {"Content-Length": length})
+    assert status == HTTPStatus.BAD_REQUEST
+
+
+@pytest.mark.parametrize(
+    ("day", "puzzle_input"), [(14, EXAMPLE_2022_14), (21, EXAMPLE_2022_21)]
+)
+def test_warm_parse_matches_fresh_solve(day: int, puzzle_input: str) -> None:
+    # both days change their data, so a shared parse would drift between requests
+    expected = load_day(2022, day).solve(puzzle_input)
+    assert solve_warm(2022, day, puzzle_input) == expected
+    assert solve_warm(2022, day, puzzle_input) == expected
@@ -0,0 +1,129 @@
+"""Check solutions that have a shortcut against the straightforward version.
+
+Each shortcut (closed forms, peephole optimizations, geometry instead of walking)
+must agree with the slow approach it replaces on the example and on random cases.
+"""
+# Standard Library
+import random
+
+# External Party
+import pytest
+
+# My Modules
+from common.template import AnswerNotFoundError
+from src_2016.assembunny import Assembunny
+from src_2016.assembunny import parse_program
+from src_2016.day1 import HEADINGS
+from src_2016.day1 import Day1
+from src_2016.day12 import EXAMPLE as EXAMPLE_2016_12
+from src_2016.day19 import simulate_steal_across
+from src_2016.day19 import steal_across
+from src_2016.day21 import scramble_step
+
+SEEDS = range(25)
+
+
+class PlainAssembunny(Assembunny):
+    """Interpreter with the loop peepholes switched off."""
+
+    def _try_multiply(self) -> bool:
+        return False
+
+    def _try_add(self) -> bool:
+        return False
+
+
+def _walk_directions(directions: str) -> tuple[int, int | None]:
+    """Return 2016 day 1 answers by stepping one block at a time."""
+    x = y = heading = 0
+    visited, first_revisit = {(x, y)}, None
+    for step in directions.split(", "):
+        heading = (heading + (1 if step[0] == "R" else -1)) % len(HEADINGS)
+        dx, dy = HEADINGS[heading]
+        for _ in range(int(step[1:])):
+            x, y = x + dx, y + dy
+            if first_revisit is None and (x, y) in visited:
+                first_revisit = abs(x) + abs(y)
+            visited.add((x, y))
+    return abs(x) + abs(y), first_revisit
+
+
+def test_steal_across_matches_simulation() -> None:
+    for elves in range(1, 500):
+        assert steal_across(elves) == simulate_steal_across(elves), elves
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_assembunny_peepholes_match_plain_run(seed: int) -> None:
+    rng = random.Random(seed)
+    program = parse_program(
+        "\n".join(
+            [
+                f"cpy {rng.randint(1, 9)} b",
+                f"cpy {rng.randint(1, 9)} d",
+                "cpy b c",
+                "inc a",
+                "dec c",
+                "jnz c -2",
+                "dec d",
+                "jnz d -5",
+                f"cpy {rng.randint(1, 9)} c",
+                "inc b",
+                "dec c",
+                "jnz c -2",
+            ]
+        )
+    )
+    start = {"a": rng.randint(0, 9)}
+    assert Assembunny(program, **start).run() == PlainAssembunny(program, **start).run()
+
+
+def test_assembunny_example_matches_plain_run() -> None:
+    program = parse_program(EXAMPLE_2016_12)
+    assert Assembunny(program).run() == PlainAssembunny(program).run()
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_segment_revisit_matches_walking(seed: int) -> None:
+    rng = random.Random(seed)
+    for _ in range(5):
+        case = ", ".join(
+            rng.choice("LR") + str(rng.randint(0, 6)) for _ in range(rng.randint(1, 12))
+        )
+        expected = _walk_directions(case)
+        day = Day1()
+        assert day.solve(case, parts="a")[0] == expected[0]
+        if expected[1] is None:
+            with pytest.raises(AnswerNotFoundError):
+                day.solve(case, parts="b")
+        else:
+            assert day.solve(case, parts="b")[1] == expected[1]
+
+
+@pytest.mark.parametrize("seed", SEEDS)
+def test_unscramble_undoes_scramble(seed: int) -> None:
+    rng = random.Random(seed)
+    password = rng.sample("abcdefgh", 8)
+    for _ in range(20):
+        x, y = sorted(rng.sample(range(8), 2))
+        a, b = rng.sample(password, 2)
+        operation = rng.choice(
+            [
+                f"swap position {x} with position {y}",
+                f"swap letter {a} with letter {b}",
+                f"rotate {rng.choice(['left', 'right'])} {x} steps",
+                f"rotate based on position of letter {a}",
+                f"reverse positions {x} through {y}",
+                f"move position {y} to position {x}",
+            ]
+        ).split()
+        scrambled = scramble_step(password, operation, undo=False)
+        assert scramble_step(scrambled, operation, undo=True) == password
+        password = scrambled
+
+
+def test_unscramble_without_a_source_password() -> None:
+    # neither "ab" nor "ba" rotates into "ab" based on the position of a
+    steps = ["rotate", "based", "on", "position", "of", "letter", "a"]
+    with pytest.raises(AnswerNotFoundError):
+        scramble_step(list("ab"), steps, undo=True)